@@ -0,0 +1,79 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use aoc_2024::{days::*, profile::Profile, Solution};
+
+// Parse, part 1 and part 2 as separate benchmarks, on the example and on the real input when there is one.
+// They show up as e.g. `day-06/part-2/input`, so `cargo bench -- day-06/part-2` runs just that one
+fn bench_day<S: Solution>(c: &mut Criterion, profile: &Profile, day: usize, example: &str) {
+    // A freshly scaffolded day has no example yet and its parts are still `todo!()`, which would panic and
+    // take the whole run down with it
+    if example.is_empty() {
+        return;
+    }
+
+    let mut inputs = vec![("example", example.to_string())];
+
+    if let Ok(real_input) = std::fs::read_to_string(profile.input_path(day)) {
+        if !real_input.is_empty() {
+            inputs.push(("input", real_input));
+        }
+    }
+
+    let mut group = c.benchmark_group(format!("day-{day:02}"));
+
+    // The slowest parts take seconds per iteration, the default of 100 samples would take forever
+    group.sample_size(10);
+
+    for (name, input) in &inputs {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(black_box(input)));
+        });
+
+        // Nothing to run the parts on when the input doesn't parse
+        let Ok(parsed) = S::parse(input) else {
+            continue;
+        };
+
+        group.bench_with_input(BenchmarkId::new("part-1", name), &parsed, |b, parsed| {
+            b.iter(|| S::part_1(black_box(parsed)));
+        });
+
+        group.bench_with_input(BenchmarkId::new("part-2", name), &parsed, |b, parsed| {
+            b.iter(|| S::part_2(black_box(parsed)));
+        });
+    }
+
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($module:ident::$day:ident: $number:literal;)*) => {
+        fn bench_all(c: &mut Criterion) {
+            let profile = Profile::load(None).unwrap();
+
+            $(
+                bench_day::<$module::$day>(c, &profile, $number, $module::EXAMPLE);
+            )*
+        }
+    };
+}
+
+bench_days! {
+    day_01::Day01: 1;
+    day_02::Day02: 2;
+    day_03::Day03: 3;
+    day_04::Day04: 4;
+    day_05::Day05: 5;
+    day_06::Day06: 6;
+    day_07::Day07: 7;
+    day_08::Day08: 8;
+    day_09::Day09: 9;
+    day_10::Day10: 10;
+    day_11::Day11: 11;
+    day_12::Day12: 12;
+}
+
+criterion_group!(benches, bench_all);
+criterion_main!(benches);
@@ -0,0 +1,214 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path};
+
+use crate::{input, parse::{self, parse_string, TomlLine}};
+
+// Known-good answers, stored in answers.toml at the crate root like this:
+//
+// [day-06.input]
+// part-1 = "4602"
+// part-2 = "1703"
+//
+// The part after the day says which input the answers are for, see `InputSource::key`.
+// Only this little subset of TOML is supported, which is all the file ever needs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    // (day, input, part) -> answer
+    entries: BTreeMap<(usize, String, usize), String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    // Nothing recorded yet
+    Unknown,
+    Correct,
+    Wrong { expected: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswersError {
+    InvalidLine { line: usize, text: String },
+    AnswerOutsideTable { line: usize },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            AnswersError::InvalidLine { line, text } => write!(f, "line {line}: can't make sense of {text:?}"),
+            AnswersError::AnswerOutsideTable { line } => {
+                write!(f, "line {line}: answer before any [day-NN.input] table")
+            },
+        };
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl Verdict {
+    pub fn mark(&self) -> &'static str {
+        return match self {
+            Verdict::Unknown => "",
+            Verdict::Correct => "✓",
+            Verdict::Wrong { .. } => "✗",
+        };
+    }
+}
+
+pub fn default_path() -> std::path::PathBuf {
+    return input::resolve("answers.toml");
+}
+
+fn quote(text: &str) -> String {
+    return format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+}
+
+// Bare keys are only letters, digits, - and _, everything else needs quotes
+fn format_key(key: &str) -> String {
+    if !key.is_empty() && key.chars().all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_')) {
+        return key.to_string();
+    }
+
+    return quote(key);
+}
+
+fn parse_key(text: &str) -> Option<String> {
+    if text.starts_with('"') {
+        return parse_string(text);
+    }
+
+    if text.is_empty() || !text.chars().all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_')) {
+        return None;
+    }
+
+    return Some(text.to_string());
+}
+
+// `day-06.input`, from between the brackets
+fn parse_table(inner: &str) -> Option<(usize, String)> {
+    let (day, input) = inner.split_once('.')?;
+    let day = day.trim().strip_prefix("day-")?.parse::<usize>().ok()?;
+
+    return Some((day, parse_key(input.trim())?));
+}
+
+// `part-1 = "4602"`
+fn parse_answer(key: &str, value: &str) -> Option<(usize, String)> {
+    let part = key.strip_prefix("part-")?.parse::<usize>().ok()?;
+
+    return Some((part, parse_string(value)?));
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, AnswersError> {
+        let mut answers = Answers::default();
+        let mut table = None;
+
+        for (line_number, line, parsed) in parse::toml_lines(text) {
+            let invalid_line = || AnswersError::InvalidLine {
+                line: line_number,
+                text: line.to_string(),
+            };
+
+            match parsed {
+                TomlLine::Table(inner) => table = Some(parse_table(inner).ok_or_else(invalid_line)?),
+                TomlLine::KeyValue(key, value) => {
+                    let (part, answer) = parse_answer(key, value).ok_or_else(invalid_line)?;
+                    let (day, input) = table.clone().ok_or(AnswersError::AnswerOutsideTable { line: line_number })?;
+
+                    answers.entries.insert((day, input, part), answer);
+                },
+                TomlLine::Invalid => return Err(invalid_line()),
+            }
+        }
+
+        return Ok(answers);
+    }
+
+    // A missing file just means nothing has been recorded yet
+    pub fn load(path: &Path) -> Result<Answers, Box<dyn std::error::Error>> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(error) => return Err(error.into()),
+        };
+
+        return Ok(Answers::parse(&text)?);
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        return std::fs::write(path, self.to_string());
+    }
+
+    pub fn get(&self, day: usize, input: &str, part: usize) -> Option<&str> {
+        return self.entries.get(&(day, input.to_string(), part)).map(String::as_str);
+    }
+
+    pub fn check(&self, day: usize, input: &str, part: usize, answer: &str) -> Verdict {
+        return match self.get(day, input, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong { expected: expected.to_string() },
+        };
+    }
+
+    // Replaces whatever was there before
+    pub fn record(&mut self, day: usize, input: &str, part: usize, answer: &str) {
+        self.entries.insert((day, input.to_string(), part), answer.to_string());
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# Known-good answers, checked by the runner and by the real input tests.")?;
+        writeln!(f, "# Written by `aoc-2024 <day|all> --record`")?;
+
+        let mut previous_table = None;
+
+        for ((day, input, part), answer) in &self.entries {
+            if previous_table != Some((day, input)) {
+                writeln!(f)?;
+                writeln!(f, "[day-{day:02}.{}]", format_key(input))?;
+
+                previous_table = Some((day, input));
+            }
+
+            writeln!(f, "part-{part} = {}", quote(answer))?;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+
+        answers.record(6, "input", 1, "4602");
+        answers.record(6, "input", 2, "1703");
+        answers.record(1, "inputs/some \"other\" input.txt", 1, "11");
+
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse("[day-06.input]\npart-1 = \"4602\"").unwrap();
+
+        assert_eq!(answers.check(6, "input", 1, "4602"), Verdict::Correct);
+        assert_eq!(answers.check(6, "input", 1, "5227"), Verdict::Wrong { expected: "4602".to_string() });
+        assert_eq!(answers.check(6, "input", 2, "1703"), Verdict::Unknown);
+        assert_eq!(answers.check(6, "example", 1, "41"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Answers::parse("part-1 = \"1\""), Err(AnswersError::AnswerOutsideTable { line: 1 }));
+        assert_eq!(
+            Answers::parse("[day-01.input]\npart-1 = 1"),
+            Err(AnswersError::InvalidLine { line: 2, text: "part-1 = 1".to_string() }),
+        );
+    }
+}
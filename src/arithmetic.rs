@@ -0,0 +1,110 @@
+use std::fmt::Display;
+
+// Arithmetic for the hot paths that can overflow on big or adversarial inputs. Normally these are just the
+// operators, which wrap silently in release builds. With the `checked-arithmetic` feature every operation
+// is checked, and an overflow panics with the operation and a description of where it happened:
+//
+// overflow: 8142637218 * 2024 (day 11, blinking at stone 8142637218)
+//
+// `context` is only called when something overflowed, so building the description costs nothing otherwise
+pub trait Number: Copy + Display {
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exponent: u32) -> Option<Self>;
+    fn pow(self, exponent: u32) -> Self;
+}
+
+macro_rules! impl_number {
+    ($($type:ty),*) => {
+        $(
+            impl Number for $type {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    return <$type>::checked_add(self, other);
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    return <$type>::checked_mul(self, other);
+                }
+
+                fn checked_pow(self, exponent: u32) -> Option<Self> {
+                    return <$type>::checked_pow(self, exponent);
+                }
+
+                fn pow(self, exponent: u32) -> Self {
+                    return <$type>::pow(self, exponent);
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u32, u64, u128, usize, i64);
+
+pub const CHECKED: bool = cfg!(feature = "checked-arithmetic");
+
+#[cold]
+fn overflow(left: impl Display, operator: char, right: impl Display, context: String) -> ! {
+    panic!("overflow: {left} {operator} {right} ({context})");
+}
+
+#[inline(always)]
+pub fn add<T: Number + std::ops::Add<Output = T>>(left: T, right: T, context: impl FnOnce() -> String) -> T {
+    if !CHECKED {
+        return left + right;
+    }
+
+    return match left.checked_add(right) {
+        Some(sum) => sum,
+        None => overflow(left, '+', right, context()),
+    };
+}
+
+#[inline(always)]
+pub fn mul<T: Number + std::ops::Mul<Output = T>>(left: T, right: T, context: impl FnOnce() -> String) -> T {
+    if !CHECKED {
+        return left * right;
+    }
+
+    return match left.checked_mul(right) {
+        Some(product) => product,
+        None => overflow(left, '*', right, context()),
+    };
+}
+
+#[inline(always)]
+pub fn pow<T: Number>(base: T, exponent: u32, context: impl FnOnce() -> String) -> T {
+    if !CHECKED {
+        return base.pow(exponent);
+    }
+
+    return match base.checked_pow(exponent) {
+        Some(power) => power,
+        None => overflow(base, '^', exponent, context()),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(add(2u64, 3, || unreachable!()), 5);
+        assert_eq!(mul(u64::MAX / 2, 2, || unreachable!()), u64::MAX - 1);
+        assert_eq!(pow(10u64, 19, || unreachable!()), 10_000_000_000_000_000_000);
+    }
+
+    #[cfg(feature = "checked-arithmetic")]
+    #[test]
+    #[should_panic(expected = "overflow: 18446744073709551615 * 2 (day 99, the test)")]
+    fn overflow_is_reported() {
+        mul(u64::MAX, 2, || "day 99, the test".to_string());
+    }
+
+    #[cfg(feature = "checked-arithmetic")]
+    #[test]
+    #[should_panic(expected = "overflow: 10 ^ 20 (day 99, the test)")]
+    fn power_overflow_is_reported() {
+        pow(10u64, 20, || "day 99, the test".to_string());
+    }
+}
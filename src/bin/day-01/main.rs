@@ -1,79 +1,128 @@
-use std::collections::HashMap;
-
-fn parse_numbers(input: String) -> (Vec<usize>, Vec<usize>) {
-    let mut left = vec![];
-    let mut right = vec![];
-
-    for line in input.lines() {
-        let parts = line.split_ascii_whitespace().collect::<Vec<_>>();
-
-        if parts.len() != 2 {
-            panic!("Length was not 2 but {}", parts.len());
-        }
-
-        left.push(parts[0].parse::<usize>().unwrap());
-        right.push(parts[1].parse::<usize>().unwrap());
-    }
-
-    return (left, right);
+use aoc_2024::{
+    days::day_01::{self, Counts, Day01, Lists},
+    input,
+    report::TableFormat,
+    Solution,
+};
+
+enum Mode {
+    Parts(Vec<usize>),
+    // Distances and similarity scores between every pair of columns
+    Matrix,
+    // How the answer to one part comes about, `top` only when it was asked for
+    Explain { part: usize, top: Option<usize>, format: TableFormat },
 }
 
-fn part_1(input: String) -> usize {
-    let (mut left, mut right) = parse_numbers(input);
+// `explain 1 [--top <k>] [--format text|csv]` or `explain 2 [--format text|csv]`, the similarity scores
+// aren't ranked so there's no top to them
+fn parse_explain(rest: &[String]) -> Option<Mode> {
+    let mut rest = rest.to_vec();
 
-    left.sort_unstable();
-    right.sort_unstable();
+    let top = input::take_value(&mut rest, "--top")?;
+    let format = TableFormat::parse(input::take_value(&mut rest, "--format")?.as_deref())?;
 
-    let cumulative_difference = left.iter().zip(right.iter()).map(|(left_el, right_el)| {
-        left_el.abs_diff(*right_el)
-    })
-    .sum();
+    let top = match top {
+        Some(top) => Some(top.parse::<usize>().ok()?),
+        None => None,
+    };
 
-    return cumulative_difference;
+    return match rest.as_slice() {
+        [part] if part == "1" => Some(Mode::Explain { part: 1, top, format }),
+        [part] if part == "2" && top.is_none() => Some(Mode::Explain { part: 2, top: None, format }),
+        _ => None,
+    };
 }
 
-fn part_2(input: String) -> usize {
-    let (left, right) = parse_numbers(input);
-
-    // Key is the digit, value is how many times that digit occurs in left/right number list
-    let mut left_counts = HashMap::<usize, usize>::new();
-    let mut right_counts = HashMap::<usize, usize>::new();
-
-    for val in left {
-        left_counts.entry(val)
-            .and_modify(|entry| *entry += 1)
-            .or_insert(1);
+fn explain(lists: &Lists, part: usize, top: Option<usize>, format: TableFormat) {
+    match (part, format) {
+        (1, TableFormat::Csv) => match top {
+            Some(top) => println!("{}", day_01::explain_top_pairs(lists, top).to_csv()),
+            None => println!("{}", day_01::explain_pairs(lists).to_csv()),
+        },
+        (1, TableFormat::Text) => {
+            let top = top.unwrap_or(10);
+
+            println!("{}", day_01::explain_pairs(lists));
+            println!();
+            println!("Largest {top} distances:");
+            println!("{}", day_01::explain_top_pairs(lists, top));
+            println!();
+            println!("Part 1: {}", Day01::part_1(lists));
+        },
+        (_, TableFormat::Csv) => println!("{}", day_01::explain_similarity(&Counts::of(lists)).to_csv()),
+        (_, TableFormat::Text) => {
+            println!("{}", day_01::explain_similarity(&Counts::of(lists)));
+            println!();
+            println!("Part 2: {}", Day01::part_2(lists));
+        },
     }
+}
 
-    for val in right {
-        right_counts.entry(val)
-            .and_modify(|entry| *entry += 1)
-            .or_insert(1);
+// Streams the input instead of reading it all first, so it copes with inputs of gigabytes.
+// With just part 2 only the counts of the distinct IDs are kept
+fn main() {
+    let usage = "[part | matrix | explain 1 [--top <k>] [--format text|csv] | explain 2 [--format text|csv]] ";
+
+    let (mode, reader) = input::open_from_args(1, day_01::EXAMPLE, usage, |rest| {
+        return match rest {
+            [] => Some(Mode::Parts(vec![1, 2])),
+            [part] if part == "1" => Some(Mode::Parts(vec![1])),
+            [part] if part == "2" => Some(Mode::Parts(vec![2])),
+            [mode] if mode == "matrix" => Some(Mode::Matrix),
+            [mode, rest @ ..] if mode == "explain" => parse_explain(rest),
+            _ => None,
+        };
+    });
+
+    if let Mode::Parts(parts) = &mode {
+        if parts == &[2] {
+            match day_01::read_counts(reader) {
+                Ok(counts) => println!("Part 2: {}", counts.similarity_score()),
+                Err(error) => {
+                    eprintln!("Invalid input: {error}");
+
+                    std::process::exit(1);
+                }
+            }
+
+            return;
+        }
     }
 
-    let mut result = 0;
-    for (element, left_count) in left_counts.into_iter() {
-        let right_count = right_counts.get(&element).unwrap_or(&0);
+    let parsed = match day_01::read_lists(reader) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("Invalid input: {error}");
 
-        result += element * left_count * right_count;
-    }
+            std::process::exit(1);
+        }
+    };
 
-    return result;
-}
+    let parts = match mode {
+        Mode::Parts(parts) => parts,
+        Mode::Matrix => {
+            println!("Distance between every pair of the {} lists:", parsed.column_count());
+            println!("{}", parsed.distance_matrix());
+            println!();
+            println!("Similarity score of every pair:");
+            println!("{}", Counts::of(&parsed).similarity_matrix());
 
-fn main() {
-    let test_input = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3".to_string();
+            return;
+        },
+        Mode::Explain { part, top, format } => {
+            explain(&parsed, part, top, format);
+
+            return;
+        },
+    };
 
-    let actual_input = std::fs::read_to_string("src/bin/day-1/input.txt").unwrap();
+    let result_1 = Day01::part_1(&parsed);
 
-    let result = part_1(actual_input.clone());
-    println!("Part 1: {result}");
+    println!("Part 1: {result_1}");
 
-    let result = part_2(actual_input);
-    println!("Part 2: {result}");
+    if parts.contains(&2) {
+        let result_2 = Day01::part_2(&parsed);
+
+        println!("Part 2: {result_2}");
+    }
 }
@@ -1,90 +1,118 @@
-struct Report(Vec<usize>);
+use aoc_2024::{
+    days::day_02::{self, Day02, SafetyPolicy},
+    input,
+    report::TableFormat,
+    Solution,
+};
+
+struct Options {
+    policy: SafetyPolicy,
+    // How many faults to tolerate on top of the two parts
+    max_faults: Option<usize>,
+    // List every unsafe report and why
+    explain: Option<TableFormat>,
+}
+
+// The policy from --policy <file> if given, then the flags for single settings on top
+fn parse_policy(arguments: &mut Vec<String>) -> Result<SafetyPolicy, String> {
+    let invalid = || "missing value".to_string();
 
-impl Report {
-    fn with_ignored_index(&self, index: usize) -> Self {
-        let mut new_levels = self.0.clone();
+    let mut policy = match input::take_value(arguments, "--policy").ok_or_else(invalid)? {
+        Some(path) => {
+            let path = input::resolve(path);
+            let text = std::fs::read_to_string(&path).map_err(|error| format!("couldn't read {}: {error}", path.display()))?;
 
-        new_levels.remove(index);
+            SafetyPolicy::parse(&text).map_err(|error| format!("{}: {error}", path.display()))?
+        },
+        None => SafetyPolicy::default(),
+    };
 
-        return Report(new_levels);
+    for (flag, key) in [("--min-step", "min_step"), ("--max-step", "max_step"), ("--direction", "direction")] {
+        if let Some(value) = input::take_value(arguments, flag).ok_or_else(invalid)? {
+            policy.set(key, &value).map_err(|error| error.to_string())?;
+        }
     }
 
-    fn is_valid(&self) -> bool {
-        // Assuming no inputs of length 1
-        let is_increasing = self.0[1] > self.0[0];
+    if input::take_flag(arguments, "--allow-plateaus") {
+        policy.allow_plateaus = true;
+    }
 
-        for (current, next) in self.0.iter().zip(self.0.iter().skip(1)) {
-            if (next > current) != is_increasing {
-                return false;
-            }
+    policy.check().map_err(|error| error.to_string())?;
 
-            if current.abs_diff(*next) == 0 || current.abs_diff(*next) > 3 {
-                return false;
-            }
-        }
+    return Ok(policy);
+}
 
-        return true;
-    }
+fn parse_options(rest: &[String]) -> Option<Options> {
+    let mut rest = rest.to_vec();
 
-    fn has_max_1_fault(&self) -> bool {
-        if self.is_valid() {
-            return true;
-        }
+    let policy = match parse_policy(&mut rest) {
+        Ok(policy) => policy,
+        Err(error) => {
+            eprintln!("Invalid policy: {error}");
 
-        for skipped_index in 0..self.0.len() {
-            if self.with_ignored_index(skipped_index).is_valid() {
-                return true;
-            }
+            return None;
         }
+    };
 
-        return false;
-    }
-}
+    let max_faults = match input::take_value(&mut rest, "--max-faults")? {
+        Some(k) => Some(k.parse::<usize>().ok()?),
+        None => None,
+    };
 
-fn part_1(input: String) -> usize {
-    let reports = input.lines()
-        .map(|line| {
-            let levels = line.split_ascii_whitespace()
-                .map(|digit| digit.parse::<usize>().unwrap())
-                .collect::<Vec<_>>();
+    let format = input::take_value(&mut rest, "--format")?;
 
-            Report(levels)
-        })
-        .collect::<Vec<_>>();
+    let explain = match input::take_flag(&mut rest, "--explain") {
+        true => Some(TableFormat::parse(format.as_deref())?),
+        // A format only makes sense for the explanation
+        false if format.is_some() => return None,
+        false => None,
+    };
 
-    return reports.into_iter().filter(Report::is_valid).count();
-}
+    if !rest.is_empty() {
+        return None;
+    }
 
-fn part_2(input: String) -> usize {
-    let reports = input.lines()
-        .map(|line| {
-            let levels = line.split_ascii_whitespace()
-                .map(|digit| digit.parse::<usize>().unwrap())
-                .collect::<Vec<_>>();
+    return Some(Options { policy, max_faults, explain });
+}
 
-            Report(levels)
-        })
-        .collect::<Vec<_>>();
+fn main() {
+    let usage = "[--policy <file>] [--min-step <n>] [--max-step <n>] [--allow-plateaus] \
+        [--direction increasing|decreasing|either] [--max-faults <k>] [--explain [--format text|csv]] ";
 
-    return reports.into_iter().filter(Report::has_max_1_fault).count();
-}
+    let (options, input) = input::read_with_args(2, day_02::EXAMPLE, usage, parse_options);
+    let policy = &options.policy;
 
+    let parsed = match Day02::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("Invalid input: {error}");
 
-fn main() {
-    let test_input = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9".to_string();
+            std::process::exit(1);
+        }
+    };
 
-    let actual_input = std::fs::read_to_string("src/bin/day-2/input.txt").unwrap();
+    // On its own, so the CSV can go straight into a file
+    if let Some(format) = options.explain {
+        println!("{}", day_02::explain(&parsed, policy).render(format));
 
-    let result_1 = part_1(actual_input.clone());
+        if format == TableFormat::Csv {
+            return;
+        }
 
-    println!("Part 1: {result_1}");
+        println!();
+    }
 
-    let result_2 = part_2(actual_input);
+    // The parts are the puzzle's, other policies get their own names
+    if *policy == SafetyPolicy::default() {
+        println!("Part 1: {}", Day02::part_1(&parsed));
+        println!("Part 2: {}", Day02::part_2(&parsed));
+    } else {
+        println!("Policy: {policy}");
+        println!("Safe: {}", day_02::count_safe(&parsed, 0, policy));
+        println!("Safe with at most 1 fault: {}", day_02::count_safe(&parsed, 1, policy));
+    }
 
-    println!("Part 2: {result_2}");
+    if let Some(k) = options.max_faults {
+        println!("Safe with at most {k} faults: {}", day_02::count_safe(&parsed, k, policy));
+    }
 }
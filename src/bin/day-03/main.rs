@@ -1,114 +1,55 @@
-fn parse_one_to_three_digit_integer(input: &str, i: &mut usize) -> Option<usize> {
-    let mut result = None;
+use aoc_2024::{days::day_03::{self, InstructionSet, Machine}, input};
 
-    if *i >= input.len() {
-        return result;
-    }
-
-    if input[*i..].len() < 3 {}
-    else if let Ok(number) = input[*i..*i + 3].parse::<usize>() {
-        *i += 3;
-
-        result = Some(number);
-    }
-
-    if input[*i..].len() < 2 {}
-    else if let Ok(number) = input[*i..*i + 2].parse::<usize>() {
-        *i += 2;
-
-        result = Some(number);
-    }
-
-    if input[*i..].is_empty() {}
-    else if let Ok(number) = input[*i..*i + 1].parse::<usize>() {
-        *i += 1;
-
-        result = Some(number);
-    }
-
-    return result;
-}
-
-fn parse_mul(input: &str, mut i: usize) -> Option<usize> {
-    if input.get(i..=i+3)? != "mul(" {
-        return None;
-    }
-    i += 4;
-
-    let left = parse_one_to_three_digit_integer(input, &mut i)?;
-
-    if input.get(i..i+1)? != "," {
-        return None;
-    }
-    i += 1;
+// `--instructions mul,add,...` runs the memory with those instead of the two parts
+fn parse_options(rest: &[String]) -> Option<Option<InstructionSet>> {
+    let mut rest = rest.to_vec();
 
-    let right = parse_one_to_three_digit_integer(input, &mut i)?;
+    let set = match input::take_value(&mut rest, "--instructions")? {
+        Some(names) => Some(InstructionSet::parse(&names)?),
+        None => None,
+    };
 
-    if input.get(i..i+1)? != ")" {
+    if !rest.is_empty() {
         return None;
     }
-    i += 1;
 
-    return Some(left * right);
+    return Some(set);
 }
 
-fn part_1(input: String) -> usize {
-    let mut result = 0;
-
-    for i in 0..input.len() {
-        if let Some(product) = parse_mul(&input, i) {
-            result += product;
-        }
-    }
-
-    return result;
-}
+// Streams the memory in chunks, both parts running side by side as the calls come in
+fn main() {
+    let usage = format!("[--instructions <{}>] ", day_03::BUILTINS.join(","));
 
-fn part_2(input: String) -> usize {
-    let mut result = 0;
+    let (set, reader) = input::open_from_args(3, day_03::EXAMPLE_2, &usage, parse_options);
 
-    let mut currently_on_do = true;
+    let custom = set.is_some();
 
-    for i in 0..input.len() {
-        if let Some("do()") = input.get(i..i+4) {
-            // println!("Now on DO due to {}", &input[i..]);
-            currently_on_do = true;
-            continue;
-        }
+    let sets = match set {
+        Some(set) => vec![set],
+        None => vec![InstructionSet::part_1(), InstructionSet::part_2()],
+    };
 
-        if let Some("don't()") = input.get(i..i+7) {
-            // println!("Now on DON'T due to {}", &input[i..]);
-            currently_on_do = false;
-            continue;
-        }
+    let mut machines = vec![Machine::default(); sets.len()];
 
-        if !currently_on_do {
-            continue;
+    let scanned = day_03::scan(reader, day_03::CHUNK_SIZE, |call| {
+        for (set, machine) in sets.iter().zip(&mut machines) {
+            set.step(machine, &call.node);
         }
+    });
 
-        // println!("Currently on DO, processing {}", &input[i..]);
+    if let Err(error) = scanned {
+        eprintln!("Couldn't read the input: {error}");
 
-        if let Some(product) = parse_mul(&input, i) {
-            // println!("\tFound {product}");
-            result += product;
-        }
+        std::process::exit(1);
     }
 
-    return result;
-}
-
-fn main() {
-    let test_input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))".to_string();
-
-    let actual_input = std::fs::read_to_string("src/bin/day-3/input.txt").unwrap();
+    if custom {
+        println!("Instructions: {}", sets[0].names().join(", "));
+        println!("Total: {}", machines[0].total);
 
-    let result_1 = part_1(actual_input.clone());
-
-    println!("Part 1: {result_1}");
-
-    let test_input_2 = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))".to_string();
-
-    let result_2 = part_2(actual_input);
+        return;
+    }
 
-    println!("Part 2: {result_2}");
+    println!("Part 1: {}", machines[0].total);
+    println!("Part 2: {}", machines[1].total);
 }
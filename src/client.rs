@@ -0,0 +1,236 @@
+use std::{fmt::Display, net::TcpStream, time::Duration};
+
+use crate::http::{self, Message};
+
+pub const YEAR: usize = 2024;
+
+// Talks to anything with the Advent of Code endpoints, at `<base url>/2024/day/6/input` and
+// `<base url>/2024/day/6/answer`. Only plain HTTP for now, which is enough for the mock server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Client {
+    host: String,
+    port: u16,
+    // Whatever comes after the host in the base URL, without a trailing /
+    prefix: String,
+    session: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientError {
+    InvalidUrl(String),
+    HttpsUnsupported(String),
+    Io(String),
+    // Anything but a 200, with the body since that's where the server explains itself
+    Status { status: String, body: String },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ClientError::InvalidUrl(url) => write!(f, "invalid server URL {url:?}, expected http://host[:port][/path]"),
+            ClientError::HttpsUnsupported(url) => {
+                write!(f, "can't talk to {url}, only http:// is supported. Point AOC_SERVER at a local serve or at a proxy that talks HTTPS")
+            },
+            ClientError::Io(error) => write!(f, "{error}"),
+            ClientError::Status { status, body } => write!(f, "the server said {status}: {}", page_text(body)),
+        };
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<std::io::Error> for ClientError {
+    fn from(error: std::io::Error) -> ClientError {
+        return ClientError::Io(error.to_string());
+    }
+}
+
+// What the server made of a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    // The server sometimes says whether it's too high or too low
+    Wrong { hint: Option<String> },
+    // Answers are rate limited, the message says how long to wait
+    TooSoon { message: String },
+    // The part is already solved, or part 1 isn't yet
+    WrongLevel,
+    // Nothing we recognise, so it's passed on as is
+    Unrecognised(String),
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            Submission::Correct => write!(f, "That's the right answer"),
+            Submission::Wrong { hint: Some(hint) } => write!(f, "That's not the right answer, it's {hint}"),
+            Submission::Wrong { hint: None } => write!(f, "That's not the right answer"),
+            Submission::TooSoon { message } => write!(f, "{message}"),
+            Submission::WrongLevel => write!(f, "That part isn't open for answers, is it already solved?"),
+            Submission::Unrecognised(message) => write!(f, "Unexpected response: {message}"),
+        };
+    }
+}
+
+// The text of an HTML page without the tags and with the whitespace collapsed. Good enough for the
+// one paragraph pages that come back from the answer endpoint
+fn page_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for character in html.chars() {
+        match character {
+            '<' => in_tag = true,
+            '>' => {
+                in_tag = false;
+
+                text.push(' ');
+            },
+            character if !in_tag => text.push(character),
+            _ => {},
+        }
+    }
+
+    return text.split_whitespace().collect::<Vec<_>>().join(" ");
+}
+
+// The answer pages are meant for people, this goes by the sentences in them
+pub fn parse_submission(html: &str) -> Submission {
+    let text = page_text(html);
+
+    if text.contains("That's the right answer") {
+        return Submission::Correct;
+    }
+
+    if text.contains("That's not the right answer") {
+        let hint = ["too high", "too low"].into_iter().find(|hint| text.contains(hint));
+
+        return Submission::Wrong { hint: hint.map(str::to_string) };
+    }
+
+    if text.contains("You gave an answer too recently") {
+        // Up to the end of the sentence with the time left in it
+        let message = match text.find("You have ") {
+            Some(start) => text[start..].split_inclusive('.').next().unwrap_or_default().to_string(),
+            None => "You gave an answer too recently".to_string(),
+        };
+
+        return Submission::TooSoon { message };
+    }
+
+    if text.contains("You don't seem to be solving the right level") {
+        return Submission::WrongLevel;
+    }
+
+    return Submission::Unrecognised(text);
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Result<Client, ClientError> {
+        if base_url.starts_with("https://") {
+            return Err(ClientError::HttpsUnsupported(base_url.to_string()));
+        }
+
+        let invalid_url = || ClientError::InvalidUrl(base_url.to_string());
+
+        let rest = base_url.strip_prefix("http://").ok_or_else(invalid_url)?;
+        let (authority, prefix) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse::<u16>().map_err(|_| invalid_url())?),
+            None => (authority, 80),
+        };
+
+        if host.is_empty() {
+            return Err(invalid_url());
+        }
+
+        return Ok(Client {
+            host: host.to_string(),
+            port,
+            prefix: prefix.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        });
+    }
+
+    fn request(&self, method: &str, path: &str, mut headers: Vec<(String, String)>, body: String) -> Result<Message, ClientError> {
+        let host = match self.port {
+            80 => self.host.clone(),
+            port => format!("{}:{port}", self.host),
+        };
+
+        headers.splice(0..0, [
+            ("Host".to_string(), host),
+            ("User-Agent".to_string(), "aoc-2024 runner".to_string()),
+            ("Cookie".to_string(), format!("session={}", self.session)),
+        ]);
+
+        let request = Message {
+            start_line: format!("{method} {}{path} HTTP/1.1", self.prefix),
+            headers,
+            body,
+        };
+
+        let mut stream = TcpStream::connect((self.host.as_str(), self.port))?;
+
+        // Better to give up than to hang forever on a server that never answers
+        stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+
+        request.write_to(&mut stream)?;
+
+        let response = http::read_message(stream)?;
+
+        // `HTTP/1.1 200 OK` -> `200 OK`
+        let status = response.start_line.split_once(' ').map(|(_, status)| status).unwrap_or_default().to_string();
+
+        if !status.starts_with("200") {
+            return Err(ClientError::Status { status, body: response.body });
+        }
+
+        return Ok(response);
+    }
+
+    pub fn fetch_input(&self, day: usize) -> Result<String, ClientError> {
+        let response = self.request("GET", &format!("/{YEAR}/day/{day}/input"), vec![], String::new())?;
+
+        return Ok(response.body);
+    }
+
+    pub fn submit(&self, day: usize, part: usize, answer: &str) -> Result<Submission, ClientError> {
+        let headers = vec![("Content-Type".to_string(), "application/x-www-form-urlencoded".to_string())];
+        let body = format!("level={part}&answer={}", http::form_encode(answer));
+
+        let response = self.request("POST", &format!("/{YEAR}/day/{day}/answer"), headers, body)?;
+
+        return Ok(parse_submission(&response.body));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_urls() {
+        let client = Client::new("http://localhost:2024/aoc/", "abc").unwrap();
+
+        assert_eq!((client.host.as_str(), client.port, client.prefix.as_str()), ("localhost", 2024, "/aoc"));
+        assert_eq!(Client::new("http://example.com", "").unwrap().port, 80);
+        assert!(matches!(Client::new("https://adventofcode.com", ""), Err(ClientError::HttpsUnsupported(_))));
+        assert!(matches!(Client::new("localhost:2024", ""), Err(ClientError::InvalidUrl(_))));
+    }
+
+    #[test]
+    fn submissions() {
+        assert_eq!(parse_submission("<article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article>"), Submission::Correct);
+        assert_eq!(
+            parse_submission("<article><p>That's not the right answer; your answer is too low.  If you're stuck...</p></article>"),
+            Submission::Wrong { hint: Some("too low".to_string()) },
+        );
+        assert_eq!(
+            parse_submission("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 38s left to wait. [Return]</p>"),
+            Submission::TooSoon { message: "You have 38s left to wait.".to_string() },
+        );
+        assert_eq!(parse_submission("<p>You don't seem to be solving the right level.  Did you already complete it?</p>"), Submission::WrongLevel);
+    }
+}
@@ -0,0 +1,405 @@
+use std::{collections::HashMap, fmt::Display, io::BufRead};
+
+use crate::{parse::Location, report::Table, Solution};
+
+pub const EXAMPLE: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+pub struct Day01;
+
+// One list per column. The puzzle has two, the lists of some teams have more; the answers are always for
+// the first two and `distance_matrix`/`Counts::similarity_matrix` compare every pair
+#[derive(Debug, Clone)]
+pub struct Lists {
+    columns: Vec<Vec<u64>>,
+}
+
+// How many times every location ID occurs in each list, which is all part 2 needs.
+// Its size depends on the number of distinct IDs, not on the length of the lists
+#[derive(Debug, Clone)]
+pub struct Counts {
+    columns: Vec<HashMap<u64, u64>>,
+}
+
+// Something for every pair of columns, [i][j] is for columns i and j
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix(pub Vec<Vec<u64>>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    // Every line needs as many columns as the first one
+    WrongColumnCount { line: usize, expected: usize, count: usize, text: String },
+    // There's nothing to compare with fewer than two
+    TooFewColumns { line: usize, count: usize, text: String },
+    InvalidNumber { location: Location, text: String },
+    // Reading the input itself failed, only happens when streaming from a file or stdin
+    Io(String),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ParseError::WrongColumnCount { line, expected, count, text } => {
+                write!(f, "line {line}: expected {expected} columns like the first line but found {count} in {text:?}")
+            },
+            ParseError::TooFewColumns { line, count, text } => {
+                write!(f, "line {line}: expected at least 2 columns but found {count} in {text:?}")
+            },
+            ParseError::InvalidNumber { location, text } => write!(f, "{location}: invalid number {text:?}"),
+            ParseError::Io(error) => write!(f, "{error}"),
+        };
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_number(line_index: usize, line: &str, part: &str) -> Result<u64, ParseError> {
+    return part.parse::<u64>().map_err(|_| ParseError::InvalidNumber {
+        location: Location::of(line_index, line, part),
+        text: part.to_string(),
+    });
+}
+
+// Into `row`, which is reused between lines since this runs for every line of the huge inputs.
+// `columns` is the column count of the first line, None while parsing that one
+fn parse_line(line_index: usize, line: &str, columns: Option<usize>, row: &mut Vec<u64>) -> Result<(), ParseError> {
+    row.clear();
+
+    for part in line.split_ascii_whitespace() {
+        row.push(parse_number(line_index, line, part)?);
+    }
+
+    return match columns {
+        Some(expected) if row.len() != expected => Err(ParseError::WrongColumnCount {
+            line: line_index + 1,
+            expected,
+            count: row.len(),
+            text: line.to_string(),
+        }),
+        None if row.len() < 2 => Err(ParseError::TooFewColumns {
+            line: line_index + 1,
+            count: row.len(),
+            text: line.to_string(),
+        }),
+        _ => Ok(()),
+    };
+}
+
+// Hands every row to `f` as it's read, reusing one line buffer, so the input never has to be in memory as a whole.
+// Returns the number of columns, 2 for an empty input
+fn for_each_row(mut reader: impl BufRead, mut f: impl FnMut(&[u64])) -> Result<usize, ParseError> {
+    let mut line = String::new();
+    let mut row = vec![];
+    let mut columns = None;
+    let mut line_index = 0;
+
+    loop {
+        line.clear();
+
+        if reader.read_line(&mut line).map_err(|error| ParseError::Io(error.to_string()))? == 0 {
+            return Ok(columns.unwrap_or(2));
+        }
+
+        parse_line(line_index, line.trim_end_matches(['\r', '\n']), columns, &mut row)?;
+
+        columns = Some(row.len());
+
+        f(&row);
+
+        line_index += 1;
+    }
+}
+
+// Every list, for part 1 which has to sort them
+pub fn read_lists(reader: impl BufRead) -> Result<Lists, ParseError> {
+    let mut columns = Vec::<Vec<u64>>::new();
+
+    let count = for_each_row(reader, |row| {
+        columns.resize_with(row.len(), Vec::new);
+
+        for (column, &id) in columns.iter_mut().zip(row) {
+            column.push(id);
+        }
+    })?;
+
+    columns.resize_with(count, Vec::new);
+
+    return Ok(Lists { columns });
+}
+
+// Just the counts for part 2, in a single pass over the input
+pub fn read_counts(reader: impl BufRead) -> Result<Counts, ParseError> {
+    let mut counts = Counts { columns: vec![] };
+
+    let count = for_each_row(reader, |row| counts.add(row))?;
+
+    counts.columns.resize_with(count, HashMap::new);
+
+    return Ok(counts);
+}
+
+// The total distance between two sorted lists
+fn distance(left: &[u64], right: &[u64]) -> u64 {
+    let cumulative_difference = left.iter().zip(right.iter()).map(|(left_el, right_el)| {
+        left_el.abs_diff(*right_el)
+    })
+    .sum();
+
+    return cumulative_difference;
+}
+
+impl Lists {
+    pub fn column_count(&self) -> usize {
+        return self.columns.len();
+    }
+
+    fn sorted_columns(&self) -> Vec<Vec<u64>> {
+        return self.columns.iter()
+            .map(|column| {
+                let mut column = column.clone();
+
+                column.sort_unstable();
+
+                return column;
+            })
+            .collect();
+    }
+
+    // Part 1 for every pair of columns, sorting each column just once
+    pub fn distance_matrix(&self) -> Matrix {
+        let sorted = self.sorted_columns();
+
+        return Matrix(sorted.iter().map(|left| sorted.iter().map(|right| distance(left, right)).collect()).collect());
+    }
+}
+
+impl Counts {
+    pub fn of(lists: &Lists) -> Counts {
+        let mut counts = Counts { columns: vec![HashMap::new(); lists.columns.len()] };
+
+        for (column, counts) in lists.columns.iter().zip(&mut counts.columns) {
+            for &id in column {
+                *counts.entry(id).or_insert(0) += 1;
+            }
+        }
+
+        return counts;
+    }
+
+    fn add(&mut self, row: &[u64]) {
+        self.columns.resize_with(row.len(), HashMap::new);
+
+        for (counts, &id) in self.columns.iter_mut().zip(row) {
+            *counts.entry(id).or_insert(0) += 1;
+        }
+    }
+
+    // Every ID in one list times how often it occurs in both
+    pub fn similarity(&self, left: usize, right: usize) -> u64 {
+        let mut result = 0;
+
+        for (element, left_count) in &self.columns[left] {
+            let right_count = self.columns[right].get(element).unwrap_or(&0);
+
+            result += element * left_count * right_count;
+        }
+
+        return result;
+    }
+
+    // Part 2, for the first two columns
+    pub fn similarity_score(&self) -> u64 {
+        return self.similarity(0, 1);
+    }
+
+    pub fn similarity_matrix(&self) -> Matrix {
+        let columns = 0..self.columns.len();
+
+        return Matrix(columns.clone().map(|left| columns.clone().map(|right| self.similarity(left, right)).collect()).collect());
+    }
+}
+
+// Every pair of the sorted first two lists as (left, right, distance, rank), where rank 1 is the largest
+// distance. In pair order, ties rank in pair order too
+fn ranked_pairs(lists: &Lists) -> Vec<(u64, u64, u64, usize)> {
+    let sorted = lists.sorted_columns();
+
+    let mut pairs = sorted[0].iter()
+        .zip(&sorted[1])
+        .map(|(&left, &right)| (left, right, left.abs_diff(right), 0))
+        .collect::<Vec<_>>();
+
+    let mut by_distance = (0..pairs.len()).collect::<Vec<_>>();
+
+    by_distance.sort_by_key(|&i| std::cmp::Reverse(pairs[i].2));
+
+    for (rank, i) in by_distance.into_iter().enumerate() {
+        pairs[i].3 = rank + 1;
+    }
+
+    return pairs;
+}
+
+// Part 1 step by step, every pair with its distance
+pub fn explain_pairs(lists: &Lists) -> Table {
+    let mut table = Table::new(&["Pair", "Left", "Right", "Distance", "Rank"]);
+
+    for (i, (left, right, distance, rank)) in ranked_pairs(lists).into_iter().enumerate() {
+        table.push(vec![(i + 1).to_string(), left.to_string(), right.to_string(), distance.to_string(), rank.to_string()]);
+    }
+
+    return table;
+}
+
+// The `top` pairs that add the most to part 1, largest first
+pub fn explain_top_pairs(lists: &Lists, top: usize) -> Table {
+    let mut pairs = ranked_pairs(lists).into_iter().enumerate().collect::<Vec<_>>();
+
+    pairs.sort_by_key(|&(_, (_, _, _, rank))| rank);
+
+    let mut table = Table::new(&["Rank", "Pair", "Left", "Right", "Distance"]);
+
+    for (i, (left, right, distance, rank)) in pairs.into_iter().take(top) {
+        table.push(vec![rank.to_string(), (i + 1).to_string(), left.to_string(), right.to_string(), distance.to_string()]);
+    }
+
+    return table;
+}
+
+// Part 2 step by step: every ID in the left list, how often it's in either list and what that adds
+pub fn explain_similarity(counts: &Counts) -> Table {
+    let mut ids = counts.columns[0].iter().collect::<Vec<_>>();
+
+    ids.sort_unstable();
+
+    let mut table = Table::new(&["ID", "Left count", "Right count", "Score"]);
+
+    for (&id, &left_count) in ids {
+        let right_count = counts.columns[1].get(&id).copied().unwrap_or(0);
+
+        table.push(vec![id.to_string(), left_count.to_string(), right_count.to_string(), (id * left_count * right_count).to_string()]);
+    }
+
+    return table;
+}
+
+// Columns numbered from 1 along both edges, everything right aligned
+impl Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.0.iter()
+            .flatten()
+            .map(|value| value.to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max(self.0.len().to_string().len());
+
+        let label_width = self.0.len().to_string().len();
+
+        write!(f, "{:label_width$}", "")?;
+
+        for column in 1..=self.0.len() {
+            write!(f, "  {column:>width$}")?;
+        }
+
+        for (i, row) in self.0.iter().enumerate() {
+            write!(f, "\n{:>label_width$}", i + 1)?;
+
+            for value in row {
+                write!(f, "  {value:>width$}")?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl Solution for Day01 {
+    type Parsed = Lists;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Lists, ParseError> {
+        return read_lists(input.as_bytes());
+    }
+
+    fn part_1(lists: &Lists) -> u64 {
+        let mut left = lists.columns[0].clone();
+        let mut right = lists.columns[1].clone();
+
+        left.sort_unstable();
+        right.sort_unstable();
+
+        return distance(&left, &right);
+    }
+
+    fn part_2(lists: &Lists) -> u64 {
+        return Counts::of(lists).similarity_score();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day01::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day01::part_1(&parsed), 11);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day01::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day01::part_2(&parsed), 31);
+    }
+
+    #[test]
+    fn streaming() {
+        // Line endings of any kind, and no newline at the end
+        let input = EXAMPLE.replace('\n', "\r\n");
+
+        assert_eq!(read_counts(input.as_bytes()).unwrap().similarity_score(), 31);
+        assert_eq!(Day01::part_1(&read_lists(input.as_bytes()).unwrap()), 11);
+        assert_eq!(
+            read_counts("3   4\n4   x\n".as_bytes()).unwrap_err(),
+            ParseError::InvalidNumber { location: Location::at(1, 4), text: "x".to_string() },
+        );
+    }
+
+    #[test]
+    fn explain() {
+        let lists = Day01::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            explain_pairs(&lists).to_csv(),
+            "Pair,Left,Right,Distance,Rank\n1,1,3,2,2\n2,2,3,1,4\n3,3,3,0,6\n4,3,4,1,5\n5,3,5,2,3\n6,4,9,5,1",
+        );
+        assert_eq!(explain_top_pairs(&lists, 2).to_csv(), "Rank,Pair,Left,Right,Distance\n1,6,4,9,5\n2,1,1,3,2");
+        assert_eq!(
+            explain_similarity(&Counts::of(&lists)).to_csv(),
+            "ID,Left count,Right count,Score\n1,1,0,0\n2,1,0,0\n3,3,3,27\n4,1,1,4",
+        );
+    }
+
+    #[test]
+    fn more_columns() {
+        // The example with a third list that's the first one again
+        let input = EXAMPLE.lines().map(|line| format!("{line}   {}", &line[..1])).collect::<Vec<_>>().join("\n");
+        let lists = Day01::parse(&input).unwrap();
+
+        assert_eq!((Day01::part_1(&lists), Day01::part_2(&lists)), (11, 31));
+        assert_eq!(lists.distance_matrix(), Matrix(vec![vec![0, 11, 0], vec![11, 0, 11], vec![0, 11, 0]]));
+        assert_eq!(read_counts(input.as_bytes()).unwrap().similarity_matrix().0[1], vec![31, 45, 31]);
+        assert_eq!(lists.distance_matrix().to_string(), "    1   2   3\n1   0  11   0\n2  11   0  11\n3   0  11   0");
+
+        assert!(matches!(Day01::parse("1 2 3\n4 5"), Err(ParseError::WrongColumnCount { line: 2, expected: 3, count: 2, .. })));
+        assert!(matches!(Day01::parse("1"), Err(ParseError::TooFewColumns { line: 1, count: 1, .. })));
+    }
+}
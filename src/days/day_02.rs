@@ -0,0 +1,551 @@
+use std::fmt::Display;
+
+use crate::{parse::{self, Location, TomlLine}, report::Table, Solution};
+
+pub const EXAMPLE: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+pub struct Day02;
+
+#[derive(Debug, Clone)]
+pub struct Report(Vec<usize>);
+
+// Which way a report has to go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    // Whichever way the first step that changes anything goes, as long as it keeps going that way
+    Either,
+}
+
+// What makes a report safe. The puzzle's rules are the default, other data can do with different ones.
+// Loaded from a file like this, or from the day 02 binary's flags:
+//
+// min_step = 1
+// max_step = 10
+// allow_plateaus = true
+// direction = "increasing"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    // How much a level can change from one to the next, not counting plateaus
+    pub min_step: usize,
+    pub max_step: usize,
+    // Whether a level can be the same as the one before
+    pub allow_plateaus: bool,
+    pub direction: Direction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyError {
+    InvalidLine { line: usize, text: String },
+    UnknownKey { line: usize, key: String },
+    InvalidValue { key: String, value: String },
+    // No step could ever be safe
+    EmptyStepRange { min_step: usize, max_step: usize },
+}
+
+// What's wrong with the step from one level to the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    // Up when the report started going down, or the other way around
+    DirectionChange { increasing: bool },
+    // Up when the policy wants it to go down, or the other way around
+    WrongDirection { increasing: bool },
+    ZeroStep,
+    StepTooSmall { step: usize },
+    StepTooLarge { step: usize },
+}
+
+// The first unsafe step of a report, `index` is the level it goes to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub fault: Fault,
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            Fault::DirectionChange { increasing: true } => write!(f, "goes down after going up"),
+            Fault::DirectionChange { increasing: false } => write!(f, "goes up after going down"),
+            Fault::WrongDirection { increasing: true } => write!(f, "goes down instead of up"),
+            Fault::WrongDirection { increasing: false } => write!(f, "goes up instead of down"),
+            Fault::ZeroStep => write!(f, "stays the same"),
+            Fault::StepTooSmall { step } => write!(f, "changes by only {step}"),
+            Fault::StepTooLarge { step } => write!(f, "changes by {step}"),
+        };
+    }
+}
+
+impl Display for PolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            PolicyError::InvalidLine { line, text } => write!(f, "line {line}: expected key = value but found {text:?}"),
+            PolicyError::UnknownKey { line, key } => {
+                write!(f, "line {line}: unknown key {key:?}, only min_step, max_step, allow_plateaus and direction are supported")
+            },
+            PolicyError::InvalidValue { key, value } => write!(f, "invalid value {value:?} for {key}"),
+            PolicyError::EmptyStepRange { min_step, max_step } => {
+                write!(f, "no step can be at least {min_step} and at most {max_step}")
+            },
+        };
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            Direction::Increasing => write!(f, "increasing"),
+            Direction::Decreasing => write!(f, "decreasing"),
+            Direction::Either => write!(f, "either"),
+        };
+    }
+}
+
+impl Display for SafetyPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plateaus = if self.allow_plateaus { "with" } else { "without" };
+
+        return write!(
+            f,
+            "steps of {} to {}, {plateaus} plateaus, direction {}",
+            self.min_step,
+            self.max_step,
+            self.direction,
+        );
+    }
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> SafetyPolicy {
+        return SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            allow_plateaus: false,
+            direction: Direction::Either,
+        };
+    }
+}
+
+impl SafetyPolicy {
+    // One setting, by the name it has in the file. Flags use the same values
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), PolicyError> {
+        let invalid_value = || PolicyError::InvalidValue { key: key.to_string(), value: value.to_string() };
+
+        match key {
+            "min_step" => self.min_step = value.parse().map_err(|_| invalid_value())?,
+            "max_step" => self.max_step = value.parse().map_err(|_| invalid_value())?,
+            "allow_plateaus" => self.allow_plateaus = value.parse().map_err(|_| invalid_value())?,
+            "direction" => self.direction = match value {
+                "increasing" => Direction::Increasing,
+                "decreasing" => Direction::Decreasing,
+                "either" => Direction::Either,
+                _ => return Err(invalid_value()),
+            },
+            _ => return Err(invalid_value()),
+        }
+
+        return Ok(());
+    }
+
+    // Anything that isn't in the file keeps its default
+    pub fn parse(text: &str) -> Result<SafetyPolicy, PolicyError> {
+        let mut policy = SafetyPolicy::default();
+
+        for (line_number, line, parsed) in parse::toml_lines(text) {
+            let invalid_line = || PolicyError::InvalidLine {
+                line: line_number,
+                text: line.to_string(),
+            };
+
+            let TomlLine::KeyValue(key, value) = parsed else {
+                return Err(invalid_line());
+            };
+
+            if !matches!(key, "min_step" | "max_step" | "allow_plateaus" | "direction") {
+                return Err(PolicyError::UnknownKey { line: line_number, key: key.to_string() });
+            }
+
+            // Strings are quoted like in TOML, numbers and booleans aren't
+            let value = parse::parse_value(value).ok_or_else(invalid_line)?;
+
+            policy.set(key, &value)?;
+        }
+
+        return Ok(policy);
+    }
+
+    // After all settings are in, since they're allowed to be out of order until then
+    pub fn check(&self) -> Result<(), PolicyError> {
+        if self.min_step > self.max_step || self.max_step == 0 {
+            return Err(PolicyError::EmptyStepRange { min_step: self.min_step, max_step: self.max_step });
+        }
+
+        return Ok(());
+    }
+
+    // What's wrong with going from one level straight to the next, when the report goes up or down
+    fn step_fault(&self, from: usize, to: usize, increasing: bool) -> Option<Fault> {
+        if from == to {
+            return (!self.allow_plateaus).then_some(Fault::ZeroStep);
+        }
+
+        if (to > from) != increasing {
+            return Some(match self.direction {
+                Direction::Either => Fault::DirectionChange { increasing },
+                _ => Fault::WrongDirection { increasing },
+            });
+        }
+
+        let step = from.abs_diff(to);
+
+        if step < self.min_step {
+            return Some(Fault::StepTooSmall { step });
+        }
+
+        if step > self.max_step {
+            return Some(Fault::StepTooLarge { step });
+        }
+
+        return None;
+    }
+
+    // The directions a report could take under this policy
+    fn directions(&self) -> &'static [bool] {
+        return match self.direction {
+            Direction::Increasing => &[true],
+            Direction::Decreasing => &[false],
+            Direction::Either => &[true, false],
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidLevel { location: Location, text: String },
+    // A report needs at least two levels to have a direction
+    TooFewLevels { line: usize, count: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ParseError::InvalidLevel { location, text } => write!(f, "{location}: invalid level {text:?}"),
+            ParseError::TooFewLevels { line, count } => {
+                write!(f, "line {line}: a report needs at least 2 levels but has {count}")
+            },
+        };
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_report(line_index: usize, line: &str) -> Result<Report, ParseError> {
+    let levels = line.split_ascii_whitespace()
+        .map(|digit| digit.parse::<usize>().map_err(|_| ParseError::InvalidLevel {
+            location: Location::of(line_index, line, digit),
+            text: digit.to_string(),
+        }))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if levels.len() < 2 {
+        return Err(ParseError::TooFewLevels {
+            line: line_index + 1,
+            count: levels.len(),
+        });
+    }
+
+    return Ok(Report(levels));
+}
+
+impl Report {
+    // The first unsafe step as if the level at `skipped` wasn't there, with the level whose step set the direction
+    // if any did yet. Indices are still those of the whole report
+    fn check(&self, policy: &SafetyPolicy, skipped: Option<usize>) -> Result<(), (Violation, Option<usize>)> {
+        let mut levels = self.0.iter().enumerate().filter(|&(i, _)| Some(i) != skipped);
+
+        let mut increasing = match policy.direction {
+            Direction::Increasing => Some(true),
+            Direction::Decreasing => Some(false),
+            Direction::Either => None,
+        };
+
+        let mut direction_set_at = None;
+
+        let Some((_, mut current)) = levels.next() else {
+            return Ok(());
+        };
+
+        for (index, next) in levels {
+            // The first step that changes anything decides the direction, unless the policy already did.
+            // Without plateaus that's the first step, since a plateau there is unsafe anyway
+            let is_increasing = match increasing {
+                Some(is_increasing) => is_increasing,
+                None if next == current && policy.allow_plateaus => {
+                    current = next;
+
+                    continue;
+                },
+                None => {
+                    direction_set_at = Some(index);
+
+                    *increasing.insert(next > current)
+                },
+            };
+
+            if let Some(fault) = policy.step_fault(*current, *next, is_increasing) {
+                return Err((Violation { index, fault }, direction_set_at));
+            }
+
+            current = next;
+        }
+
+        return Ok(());
+    }
+
+    // Why the report isn't safe, if it isn't
+    pub fn validate(&self, policy: &SafetyPolicy) -> Result<(), Violation> {
+        return self.check(policy, None).map_err(|(violation, _)| violation);
+    }
+
+    fn is_valid(&self) -> bool {
+        return self.validate(&SafetyPolicy::default()).is_ok();
+    }
+
+    // Which level to remove to make an unsafe report safe, if one will do. The removed level has to be one of
+    // the two levels of the first unsafe step or the one before them, or one of the levels of the step that set
+    // the direction. Any other removal leaves both the direction and the unsafe step as they are: the levels
+    // before the direction was set are all the same, and the ones in between stay in between
+    pub fn fix_with_one_removal(&self, policy: &SafetyPolicy) -> Option<usize> {
+        let Err((violation, direction_set_at)) = self.check(policy, None) else {
+            return None;
+        };
+
+        let around_violation = violation.index.saturating_sub(2)..=violation.index;
+        let around_direction = direction_set_at.into_iter().flat_map(|index| index.saturating_sub(1)..=index);
+
+        let mut candidates = around_direction.chain(around_violation).collect::<Vec<_>>();
+
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        return candidates.into_iter().find(|&index| self.check(policy, Some(index)).is_ok());
+    }
+
+    // The fewest levels to remove to make the report safe going in one direction, if that's at most k.
+    // removals[i] is the fewest removals that keep level i as the last one of a safe run so far. Level i can
+    // only follow one of the k + 1 levels before it, any further back would take more than k removals,
+    // so this is O(n * k) without ever copying the levels
+    fn min_removals(&self, k: usize, policy: &SafetyPolicy, increasing: bool) -> Option<usize> {
+        let levels = &self.0;
+        let mut removals = Vec::with_capacity(levels.len());
+        let mut best = None;
+
+        for i in 0..levels.len() {
+            // Dropping everything before it always works
+            let mut fewest = i;
+
+            for j in i.saturating_sub(k + 1)..i {
+                if removals[j] <= k && policy.step_fault(levels[j], levels[i], increasing).is_none() {
+                    fewest = fewest.min(removals[j] + (i - j - 1));
+                }
+            }
+
+            removals.push(fewest);
+
+            // And dropping everything after it
+            let total = fewest + (levels.len() - 1 - i);
+
+            if total <= k && best.is_none_or(|best| total < best) {
+                best = Some(total);
+            }
+        }
+
+        return best;
+    }
+
+    // Safe after removing at most k levels, part 2 is k = 1 with the default policy
+    pub fn has_max_k_faults(&self, k: usize, policy: &SafetyPolicy) -> bool {
+        return policy.directions().iter().any(|&increasing| self.min_removals(k, policy, increasing).is_some());
+    }
+}
+
+// How many reports are safe after removing at most k levels
+pub fn count_safe(reports: &[Report], k: usize, policy: &SafetyPolicy) -> usize {
+    return reports.iter().filter(|report| report.has_max_k_faults(k, policy)).count();
+}
+
+// Every unsafe report, why it's unsafe and which level to remove to make it safe if any.
+// Lines and levels are counted from 1
+pub fn explain(reports: &[Report], policy: &SafetyPolicy) -> Table {
+    let mut table = Table::new(&["Line", "Levels", "Unsafe at level", "Because it", "Safe without level"]);
+
+    for (i, report) in reports.iter().enumerate() {
+        let Err(violation) = report.validate(policy) else {
+            continue;
+        };
+
+        let levels = report.0.iter().map(usize::to_string).collect::<Vec<_>>().join(" ");
+        let fix = report.fix_with_one_removal(policy).map(|index| (index + 1).to_string()).unwrap_or("-".to_string());
+
+        table.push(vec![(i + 1).to_string(), levels, (violation.index + 1).to_string(), violation.fault.to_string(), fix]);
+    }
+
+    return table;
+}
+
+impl Solution for Day02 {
+    type Parsed = Vec<Report>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Vec<Report>, ParseError> {
+        return input.lines()
+            .enumerate()
+            .map(|(i, line)| parse_report(i, line))
+            .collect();
+    }
+
+    fn part_1(reports: &Vec<Report>) -> usize {
+        return reports.iter().filter(|report| report.is_valid()).count();
+    }
+
+    fn part_2(reports: &Vec<Report>) -> usize {
+        return count_safe(reports, 1, &SafetyPolicy::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day02::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day02::part_1(&parsed), 2);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day02::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day02::part_2(&parsed), 4);
+    }
+
+    // Tries every way of removing up to k levels
+    fn brute_force(report: &Report, k: usize, policy: &SafetyPolicy) -> bool {
+        use itertools::Itertools;
+
+        let n = report.0.len();
+
+        return (0..=k.min(n)).any(|removed| {
+            return (0..n).combinations(n - removed).any(|kept| {
+                let levels = kept.iter().map(|&i| report.0[i]).collect::<Vec<_>>();
+
+                return Report(levels).validate(policy).is_ok();
+            });
+        });
+    }
+
+    #[test]
+    fn diagnostics() {
+        let reports = Day02::parse(EXAMPLE).unwrap();
+
+        let policy = SafetyPolicy::default();
+        let violations = reports.iter().map(|report| report.validate(&policy).err()).collect::<Vec<_>>();
+
+        assert_eq!(violations, [
+            None,
+            Some(Violation { index: 2, fault: Fault::StepTooLarge { step: 5 } }),
+            Some(Violation { index: 3, fault: Fault::StepTooLarge { step: 4 } }),
+            Some(Violation { index: 2, fault: Fault::DirectionChange { increasing: true } }),
+            Some(Violation { index: 3, fault: Fault::ZeroStep }),
+            None,
+        ]);
+
+        let fixes = reports.iter().map(|report| report.fix_with_one_removal(&policy)).collect::<Vec<_>>();
+
+        assert_eq!(fixes, [None, None, None, Some(1), Some(2), None]);
+
+        // Removing the first level changes the direction
+        assert_eq!(Day02::parse("5 6 4 3 2").unwrap()[0].fix_with_one_removal(&policy), Some(0));
+
+        assert_eq!(
+            explain(&reports, &policy).to_csv(),
+            "Line,Levels,Unsafe at level,Because it,Safe without level\n\
+            2,1 2 7 8 9,3,changes by 5,-\n\
+            3,9 7 6 2 1,4,changes by 4,-\n\
+            4,1 3 2 4 5,3,goes down after going up,2\n\
+            5,8 6 4 4 1,4,stays the same,3",
+        );
+    }
+
+    // Every report against every policy: the fast checks have to agree with trying every removal
+    fn check_against_brute_force(reports: &[Report], policy: &SafetyPolicy) {
+        for report in reports {
+            for k in 0..4 {
+                assert_eq!(report.has_max_k_faults(k, policy), brute_force(report, k, policy), "{report:?} with k = {k}, {policy}");
+            }
+
+            if report.validate(policy).is_err() {
+                assert_eq!(report.fix_with_one_removal(policy).is_some(), report.has_max_k_faults(1, policy), "{report:?}, {policy}");
+            }
+        }
+    }
+
+    #[test]
+    fn more_faults() {
+        let reports = Day02::parse(&format!("{EXAMPLE}\n1 2 9 9 3 4\n10 1 2 20 3 4 30\n5 5 5 5\n1 9")).unwrap();
+        let policy = SafetyPolicy::default();
+
+        assert!(!reports[6].has_max_k_faults(1, &policy));
+        assert!(reports[6].has_max_k_faults(2, &policy));
+
+        check_against_brute_force(&reports, &policy);
+    }
+
+    #[test]
+    fn policies() {
+        let policy = SafetyPolicy::parse("# Sensors\nmin_step = 2\nmax_step = 10\nallow_plateaus = true\ndirection = \"increasing\"\n").unwrap();
+
+        assert_eq!(policy, SafetyPolicy { min_step: 2, max_step: 10, allow_plateaus: true, direction: Direction::Increasing });
+
+        let reports = Day02::parse("1 3 3 13 15\n1 2 4\n9 7 5\n4 4 4\n1 1 2 2 1 1\n2 2 2 2 1 3").unwrap();
+        let violations = reports.iter().map(|report| report.validate(&policy).err().map(|violation| violation.fault)).collect::<Vec<_>>();
+
+        assert_eq!(violations, [
+            None,
+            Some(Fault::StepTooSmall { step: 1 }),
+            Some(Fault::WrongDirection { increasing: true }),
+            None,
+            Some(Fault::StepTooSmall { step: 1 }),
+            Some(Fault::WrongDirection { increasing: true }),
+        ]);
+
+        // With plateaus the direction can be set well after the start, and removing the level that set it fixes this one
+        let either = SafetyPolicy { allow_plateaus: true, ..SafetyPolicy::default() };
+
+        assert_eq!(reports[4].fix_with_one_removal(&either), None);
+        assert_eq!(Day02::parse("1 2 2 2 2 1").unwrap()[0].fix_with_one_removal(&either), Some(0));
+
+        for policy in [policy, either, SafetyPolicy { direction: Direction::Decreasing, ..SafetyPolicy::default() }] {
+            check_against_brute_force(&reports, &policy);
+        }
+
+        assert!(matches!(SafetyPolicy::parse("steps = 3"), Err(PolicyError::UnknownKey { line: 1, .. })));
+        assert!(matches!(SafetyPolicy::parse("direction = \"sideways\""), Err(PolicyError::InvalidValue { .. })));
+        assert_eq!(
+            SafetyPolicy { min_step: 4, ..SafetyPolicy::default() }.check(),
+            Err(PolicyError::EmptyStepRange { min_step: 4, max_step: 3 }),
+        );
+    }
+}
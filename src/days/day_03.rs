@@ -0,0 +1,705 @@
+use std::{
+    convert::Infallible,
+    io::{self, Read},
+    ops::Range,
+};
+
+use crate::Solution;
+
+pub const EXAMPLE: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+pub const EXAMPLE_2: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+pub struct Day03;
+
+// Anything together with the byte range of the input it came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub node: T,
+    pub span: (usize, usize),
+}
+
+impl<T> Spanned<T> {
+    pub fn range(&self) -> Range<usize> {
+        return self.span.0..self.span.1;
+    }
+}
+
+// Letters stick together into words, apostrophes included for don't. Everything that isn't a word, a
+// number or punctuation of a call is a byte of garbage on its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    // Only the last `MAX_WORD` letters, which is all that's needed to see which instruction it ends with
+    Word(String),
+    // None when it has more digits than an operand can have
+    Number(Option<i64>),
+    OpenParen,
+    CloseParen,
+    Comma,
+    Other(u8),
+}
+
+// A call of anything that looks like an instruction, `xmul(2,4)` is the word "xmul" with 2 and 4. Which
+// instruction it is, if any, is up to the instruction set that runs it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    pub word: String,
+    pub arguments: Vec<i64>,
+}
+
+// Names of instructions can't be longer than this
+pub const MAX_WORD: usize = 32;
+
+// The numbers in calls have 1 to 3 digits, anything longer makes the call garbage
+const MAX_DIGITS: usize = 3;
+
+// How much is read at a time when scanning a reader
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+fn is_word_byte(byte: u8) -> bool {
+    return byte.is_ascii_alphabetic() || byte == b'\'';
+}
+
+#[derive(Debug, Clone)]
+enum Run {
+    Word(String),
+    Number { digits: usize, value: i64 },
+}
+
+// Takes the memory a byte at a time, so it can come in chunks of any size. A word or number is only
+// complete once the byte after it is in, until then it's kept as the current run
+#[derive(Debug, Clone, Default)]
+pub struct Lexer {
+    position: usize,
+    // Where it started, and what it is so far
+    run: Option<(usize, Run)>,
+}
+
+impl Lexer {
+    pub fn new() -> Lexer {
+        return Lexer::default();
+    }
+
+    pub fn push(&mut self, byte: u8, emit: &mut impl FnMut(Spanned<Token>)) {
+        let position = self.position;
+
+        self.position += 1;
+
+        match &mut self.run {
+            Some((_, Run::Word(word))) if is_word_byte(byte) => {
+                if word.len() == MAX_WORD {
+                    word.remove(0);
+                }
+
+                word.push(byte as char);
+
+                return;
+            },
+            Some((_, Run::Number { digits, value })) if byte.is_ascii_digit() => {
+                *digits += 1;
+
+                if *digits <= MAX_DIGITS {
+                    *value = *value * 10 + (byte - b'0') as i64;
+                }
+
+                return;
+            },
+            _ => {},
+        }
+
+        self.end_run(position, emit);
+
+        let node = match byte {
+            _ if is_word_byte(byte) => {
+                self.run = Some((position, Run::Word((byte as char).to_string())));
+
+                return;
+            },
+            b'0'..=b'9' => {
+                self.run = Some((position, Run::Number { digits: 1, value: (byte - b'0') as i64 }));
+
+                return;
+            },
+            b'(' => Token::OpenParen,
+            b')' => Token::CloseParen,
+            b',' => Token::Comma,
+            _ => Token::Other(byte),
+        };
+
+        emit(Spanned { node, span: (position, position + 1) });
+    }
+
+    // The end of the input also ends whatever run there was
+    pub fn finish(&mut self, emit: &mut impl FnMut(Spanned<Token>)) {
+        self.end_run(self.position, emit);
+    }
+
+    fn end_run(&mut self, end: usize, emit: &mut impl FnMut(Spanned<Token>)) {
+        let Some((start, run)) = self.run.take() else {
+            return;
+        };
+
+        let node = match run {
+            Run::Word(word) => Token::Word(word),
+            Run::Number { digits, value } => Token::Number((digits <= MAX_DIGITS).then_some(value)),
+        };
+
+        emit(Spanned { node, span: (start, end) });
+    }
+}
+
+pub fn tokenize(input: &[u8]) -> Vec<Spanned<Token>> {
+    let mut lexer = Lexer::new();
+    let mut tokens = vec![];
+
+    for &byte in input {
+        lexer.push(byte, &mut |token| tokens.push(token));
+    }
+
+    lexer.finish(&mut |token| tokens.push(token));
+
+    return tokens;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expecting {
+    OpenParen,
+    ArgumentOrClose,
+    Argument,
+    CommaOrClose,
+}
+
+// Takes the tokens one at a time and hands out every call once its closing parenthesis is in. The garbage
+// in between is skipped, and a broken call only costs its word, so `mul(mul(2,3)` still finds the second one
+#[derive(Debug, Clone, Default)]
+pub struct Parser {
+    // The call so far, where it started and what has to come next
+    call: Option<(Call, usize, Expecting)>,
+}
+
+impl Parser {
+    pub fn new() -> Parser {
+        return Parser::default();
+    }
+
+    pub fn push(&mut self, token: Spanned<Token>) -> Option<Spanned<Call>> {
+        let Some((mut call, start, expecting)) = self.call.take() else {
+            self.start(token);
+
+            return None;
+        };
+
+        let expecting = match (expecting, &token.node) {
+            (Expecting::OpenParen, Token::OpenParen) => Expecting::ArgumentOrClose,
+            (Expecting::ArgumentOrClose | Expecting::Argument, &Token::Number(Some(value))) => {
+                call.arguments.push(value);
+
+                Expecting::CommaOrClose
+            },
+            (Expecting::CommaOrClose, Token::Comma) => Expecting::Argument,
+            (Expecting::ArgumentOrClose | Expecting::CommaOrClose, Token::CloseParen) => {
+                return Some(Spanned { node: call, span: (start, token.span.1) });
+            },
+            // Nothing but a word can start the next call, and only this token could be one
+            _ => {
+                self.start(token);
+
+                return None;
+            },
+        };
+
+        self.call = Some((call, start, expecting));
+
+        return None;
+    }
+
+    fn start(&mut self, token: Spanned<Token>) {
+        if let Token::Word(word) = token.node {
+            self.call = Some((Call { word, arguments: vec![] }, token.span.0, Expecting::OpenParen));
+        }
+    }
+}
+
+// The lexer and parser together, for memory that comes in chunks. An instruction split between two
+// chunks is found all the same, and every byte is looked at once
+#[derive(Debug, Clone, Default)]
+pub struct Scanner {
+    lexer: Lexer,
+    parser: Parser,
+}
+
+impl Scanner {
+    pub fn new() -> Scanner {
+        return Scanner::default();
+    }
+
+    pub fn feed(&mut self, chunk: &[u8], on_call: &mut impl FnMut(Spanned<Call>)) {
+        let parser = &mut self.parser;
+
+        let mut emit = |token| {
+            if let Some(call) = parser.push(token) {
+                on_call(call);
+            }
+        };
+
+        for &byte in chunk {
+            self.lexer.push(byte, &mut emit);
+        }
+    }
+
+    pub fn finish(mut self, on_call: &mut impl FnMut(Spanned<Call>)) {
+        let parser = &mut self.parser;
+
+        self.lexer.finish(&mut |token| {
+            if let Some(call) = parser.push(token) {
+                on_call(call);
+            }
+        });
+    }
+}
+
+// Every call from a reader, without ever holding more than a chunk of it
+pub fn scan(mut reader: impl Read, chunk_size: usize, mut on_call: impl FnMut(Spanned<Call>)) -> io::Result<()> {
+    let mut scanner = Scanner::new();
+    let mut chunk = vec![0; chunk_size];
+
+    loop {
+        let length = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(length) => length,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+
+        scanner.feed(&chunk[..length], &mut on_call);
+    }
+
+    scanner.finish(&mut on_call);
+
+    return Ok(());
+}
+
+// Every call in the memory, in order
+pub fn parse_program(input: &str) -> Vec<Spanned<Call>> {
+    let mut scanner = Scanner::new();
+    let mut program = vec![];
+
+    scanner.feed(input.as_bytes(), &mut |call| program.push(call));
+    scanner.finish(&mut |call| program.push(call));
+
+    return program;
+}
+
+// What the instructions work on: the total of everything that was computed, and whether instructions are
+// enabled. That's a stack so scopes can be nested, the innermost one decides
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub total: i64,
+    enabled: Vec<bool>,
+}
+
+impl Default for Machine {
+    fn default() -> Machine {
+        return Machine { total: 0, enabled: vec![true] };
+    }
+}
+
+impl Machine {
+    pub fn enabled(&self) -> bool {
+        return *self.enabled.last().unwrap();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        *self.enabled.last_mut().unwrap() = enabled;
+    }
+
+    // A new scope starts out like the one around it
+    pub fn push_scope(&mut self) {
+        self.enabled.push(self.enabled());
+    }
+
+    // Back to the state from before the scope. There's always the outermost scope left, a pop too many is
+    // just more corruption
+    pub fn pop_scope(&mut self) {
+        if self.enabled.len() > 1 {
+            self.enabled.pop();
+        }
+    }
+}
+
+// An instruction a machine can run. A call is this instruction if the word ends with the name and it has
+// the right number of arguments. The corruption can run right up to the name, like in xmul(2,4)
+pub trait Operation {
+    fn name(&self) -> &str;
+    fn arity(&self) -> usize;
+    // Runs whether or not the machine is enabled, an instruction that does something needs to check
+    fn execute(&self, machine: &mut Machine, arguments: &[i64]);
+}
+
+// The instructions from the puzzle, and some more
+pub struct Mul;
+pub struct Add;
+pub struct Sub;
+pub struct Do;
+pub struct Dont;
+pub struct Push;
+pub struct Pop;
+
+impl Operation for Mul {
+    fn name(&self) -> &str {
+        return "mul";
+    }
+
+    fn arity(&self) -> usize {
+        return 2;
+    }
+
+    fn execute(&self, machine: &mut Machine, arguments: &[i64]) {
+        if machine.enabled() {
+            machine.total += arguments[0] * arguments[1];
+        }
+    }
+}
+
+impl Operation for Add {
+    fn name(&self) -> &str {
+        return "add";
+    }
+
+    fn arity(&self) -> usize {
+        return 2;
+    }
+
+    fn execute(&self, machine: &mut Machine, arguments: &[i64]) {
+        if machine.enabled() {
+            machine.total += arguments[0] + arguments[1];
+        }
+    }
+}
+
+impl Operation for Sub {
+    fn name(&self) -> &str {
+        return "sub";
+    }
+
+    fn arity(&self) -> usize {
+        return 2;
+    }
+
+    fn execute(&self, machine: &mut Machine, arguments: &[i64]) {
+        if machine.enabled() {
+            machine.total += arguments[0] - arguments[1];
+        }
+    }
+}
+
+impl Operation for Do {
+    fn name(&self) -> &str {
+        return "do";
+    }
+
+    fn arity(&self) -> usize {
+        return 0;
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[i64]) {
+        machine.set_enabled(true);
+    }
+}
+
+impl Operation for Dont {
+    fn name(&self) -> &str {
+        return "don't";
+    }
+
+    fn arity(&self) -> usize {
+        return 0;
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[i64]) {
+        machine.set_enabled(false);
+    }
+}
+
+impl Operation for Push {
+    fn name(&self) -> &str {
+        return "push";
+    }
+
+    fn arity(&self) -> usize {
+        return 0;
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[i64]) {
+        machine.push_scope();
+    }
+}
+
+impl Operation for Pop {
+    fn name(&self) -> &str {
+        return "pop";
+    }
+
+    fn arity(&self) -> usize {
+        return 0;
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[i64]) {
+        machine.pop_scope();
+    }
+}
+
+pub const BUILTINS: [&str; 7] = ["mul", "add", "sub", "do", "don't", "push", "pop"];
+
+pub fn builtin(name: &str) -> Option<Box<dyn Operation>> {
+    return match name {
+        "mul" => Some(Box::new(Mul)),
+        "add" => Some(Box::new(Add)),
+        "sub" => Some(Box::new(Sub)),
+        "do" => Some(Box::new(Do)),
+        "don't" => Some(Box::new(Dont)),
+        "push" => Some(Box::new(Push)),
+        "pop" => Some(Box::new(Pop)),
+        _ => None,
+    };
+}
+
+// The instructions a machine knows, every other call is garbage
+#[derive(Default)]
+pub struct InstructionSet {
+    operations: Vec<Box<dyn Operation>>,
+}
+
+impl InstructionSet {
+    pub fn new() -> InstructionSet {
+        return InstructionSet::default();
+    }
+
+    // Part 1 only knows about multiplying
+    pub fn part_1() -> InstructionSet {
+        let mut set = InstructionSet::new();
+
+        set.register(Mul);
+
+        return set;
+    }
+
+    pub fn part_2() -> InstructionSet {
+        let mut set = InstructionSet::part_1();
+
+        set.register(Do);
+        set.register(Dont);
+
+        return set;
+    }
+
+    // From a list like "mul,do,don't", each a builtin. None if one isn't
+    pub fn parse(names: &str) -> Option<InstructionSet> {
+        let mut set = InstructionSet::new();
+
+        for name in names.split(',') {
+            set.operations.push(builtin(name.trim())?);
+        }
+
+        return Some(set);
+    }
+
+    pub fn register(&mut self, operation: impl Operation + 'static) {
+        assert!(operation.name().len() <= MAX_WORD, "Instruction names can't be longer than {MAX_WORD} bytes");
+
+        // The scanner only ever reads words of these, any other name would never be called
+        assert!(
+            !operation.name().is_empty() && operation.name().bytes().all(is_word_byte),
+            "Instruction names can only be letters and apostrophes, not {:?}",
+            operation.name(),
+        );
+
+        self.operations.push(Box::new(operation));
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        return self.operations.iter().map(|operation| operation.name()).collect();
+    }
+
+    // The longest name wins when there's a choice, `don't()` isn't some `t()`
+    pub fn resolve(&self, call: &Call) -> Option<&dyn Operation> {
+        return self.operations.iter()
+            .filter(|operation| call.word.ends_with(operation.name()) && operation.arity() == call.arguments.len())
+            .max_by_key(|operation| operation.name().len())
+            .map(|operation| operation.as_ref());
+    }
+
+    // Calls that aren't an instruction of this set are skipped
+    pub fn step(&self, machine: &mut Machine, call: &Call) {
+        if let Some(operation) = self.resolve(call) {
+            operation.execute(machine, &call.arguments);
+        }
+    }
+
+    pub fn run(&self, program: &[Spanned<Call>]) -> Machine {
+        let mut machine = Machine::default();
+
+        for call in program {
+            self.step(&mut machine, &call.node);
+        }
+
+        return machine;
+    }
+}
+
+impl Solution for Day03 {
+    // Just the calls, both parts run them with their own instruction set
+    type Parsed = Vec<Spanned<Call>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    // Corrupted memory is the whole point, anything goes
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Vec<Spanned<Call>>, Infallible> {
+        return Ok(parse_program(input));
+    }
+
+    fn part_1(program: &Vec<Spanned<Call>>) -> i64 {
+        return InstructionSet::part_1().run(program).total;
+    }
+
+    fn part_2(program: &Vec<Spanned<Call>>) -> i64 {
+        return InstructionSet::part_2().run(program).total;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day03::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day03::part_1(&parsed), 161);
+    }
+
+    // Part 2 has its own example with do() and don't() in it
+    #[test]
+    fn part_2_example() {
+        let parsed = Day03::parse(EXAMPLE_2).unwrap();
+
+        assert_eq!(Day03::part_2(&parsed), 48);
+    }
+
+    #[test]
+    fn lexer() {
+        let tokens = tokenize(b"xmul(2,40)don't?1234").into_iter().map(|token| (token.range(), token.node)).collect::<Vec<_>>();
+
+        assert_eq!(tokens, [
+            (0..4, Token::Word("xmul".to_string())),
+            (4..5, Token::OpenParen),
+            (5..6, Token::Number(Some(2))),
+            (6..7, Token::Comma),
+            (7..9, Token::Number(Some(40))),
+            (9..10, Token::CloseParen),
+            (10..15, Token::Word("don't".to_string())),
+            (15..16, Token::Other(b'?')),
+            (16..20, Token::Number(None)),
+        ]);
+    }
+
+    #[test]
+    fn program() {
+        let program = parse_program(EXAMPLE_2);
+        let set = InstructionSet::part_2();
+
+        let located = program.iter()
+            .filter_map(|call| Some((set.resolve(&call.node)?.name(), &call.node.arguments[..], &EXAMPLE_2[call.range()])))
+            .collect::<Vec<_>>();
+
+        assert_eq!(located, [
+            ("mul", &[2, 4][..], "xmul(2,4)"),
+            ("don't", &[], "don't()"),
+            ("mul", &[5, 5], "mul(5,5)"),
+            ("mul", &[11, 8], "mul(11,8)"),
+            ("do", &[], "undo()"),
+            ("mul", &[8, 5], "mul(8,5)"),
+        ]);
+
+        // Too many digits, a broken call right before a good one, and calls with the wrong arguments
+        let program = parse_program("mul(1234,5)mul(mul(2,3)do(1)don't(,)mul(4,5,6)");
+        let calls = program.iter().map(|call| (call.node.word.as_str(), &call.node.arguments[..])).collect::<Vec<_>>();
+
+        assert_eq!(calls, [("mul", &[2, 3][..]), ("do", &[1]), ("mul", &[4, 5, 6])]);
+        assert_eq!(set.run(&program).total, 6);
+    }
+
+    #[test]
+    fn extended() {
+        let program = parse_program("add(1,2)push()don't()mul(5,5)push()do()sub(2,10)pop()mul(5,5)pop()pop()mul(3,3)");
+
+        // Without scopes the do() inside one turns everything back on
+        assert_eq!(InstructionSet::part_2().run(&program).total, 25 + 9);
+        assert_eq!(InstructionSet::parse("add,sub,mul,do,don't,push,pop").unwrap().run(&program).total, 3 - 8 + 9);
+
+        // Any other instruction can be plugged in
+        struct Square;
+
+        impl Operation for Square {
+            fn name(&self) -> &str {
+                return "square";
+            }
+
+            fn arity(&self) -> usize {
+                return 1;
+            }
+
+            fn execute(&self, machine: &mut Machine, arguments: &[i64]) {
+                machine.total += arguments[0] * arguments[0];
+            }
+        }
+
+        let mut set = InstructionSet::part_1();
+
+        set.register(Square);
+
+        assert_eq!(set.run(&parse_program("mul(2,3)xsquare(12)square(1,2)")).total, 6 + 144);
+        assert!(InstructionSet::parse("mul,div").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Instruction names can only be letters and apostrophes, not \"mul2\"")]
+    fn unreachable_name() {
+        struct Mul2;
+
+        impl Operation for Mul2 {
+            fn name(&self) -> &str {
+                return "mul2";
+            }
+
+            fn arity(&self) -> usize {
+                return 2;
+            }
+
+            fn execute(&self, machine: &mut Machine, arguments: &[i64]) {
+                machine.total += 2 * arguments[0] * arguments[1];
+            }
+        }
+
+        InstructionSet::part_1().register(Mul2);
+    }
+
+    // Every possible split between chunks, including in the middle of a name, a number and a long word
+    #[test]
+    fn chunks() {
+        let input = format!("{EXAMPLE_2}{}don't()mul(123,4567)mul(99,9)", "x".repeat(2 * MAX_WORD));
+        let expected = parse_program(&input);
+
+        assert_eq!(expected.last().unwrap().node, Call { word: "mul".to_string(), arguments: vec![99, 9] });
+        assert_eq!(expected[expected.len() - 2].node.word, format!("{}don't", "x".repeat(MAX_WORD - 5)));
+
+        for chunk_size in 1..=input.len() {
+            let mut program = vec![];
+
+            scan(input.as_bytes(), chunk_size, |call| program.push(call)).unwrap();
+
+            assert_eq!(program, expected, "chunks of {chunk_size}");
+        }
+    }
+}
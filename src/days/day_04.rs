@@ -0,0 +1,155 @@
+use itertools::Itertools;
+
+use crate::{geometry::{Diagonal, Offset, Position, EIGHT_OFFSETS}, grid::{Grid, GridError}, Solution};
+
+pub const EXAMPLE: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+pub struct Day04;
+
+pub struct Puzzle(Grid<char>);
+
+impl Puzzle {
+    fn find_word(&self, position: Position, word: &[char], direction: Offset) -> bool {
+        // Base case (found)
+        if word.is_empty() {
+            return true;
+        }
+
+        let new_position = self.0.offset(position, direction);
+        if new_position.is_none() {
+            return false;
+        }
+
+        let new_position = new_position.unwrap();
+
+        // Other base case (not found)
+        if self.0[new_position] != word[0] {
+            return false;
+        }
+
+        return self.find_word(new_position, &word[1..], direction);
+    }
+}
+
+fn parse_input(input: &str) -> Result<Puzzle, GridError> {
+    return Ok(Puzzle(Grid::parse_chars(input)?));
+}
+
+impl Puzzle {
+    fn char_at_offset_equals(&self, position: Position, offset: Offset, character: char) -> bool {
+        let position = self.0.offset(position, offset);
+        if position.is_none() {
+            return false;
+        }
+
+        return self.0[position.unwrap()] == character;
+    }
+
+    #[allow(non_snake_case)]
+    fn this_A_is_surrounded_by(&self, position: Position, offsets: &[Offset]) -> bool {
+        assert_eq!(offsets.len(), 4); // Little sanity check
+
+        return self.char_at_offset_equals(position, offsets[0], 'M')
+            && self.char_at_offset_equals(position, offsets[1], 'M')
+            && self.char_at_offset_equals(position, offsets[2], 'S')
+            && self.char_at_offset_equals(position, offsets[3], 'S');
+    }
+}
+
+impl Solution for Day04 {
+    type Parsed = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = GridError;
+
+    fn parse(input: &str) -> Result<Puzzle, GridError> {
+        return parse_input(input);
+    }
+
+    fn part_1(input: &Puzzle) -> usize {
+        let (rows, cols) = input.0.bounds();
+
+        let mut found_count = 0;
+
+        for (row, col) in (0..rows).cartesian_product(0..cols) {
+            let position = Position::new(row, col);
+
+            if input.0[position] != 'X' {
+                continue;
+            }
+
+            for direction in EIGHT_OFFSETS {
+                if input.find_word(position, &['M', 'A', 'S'], direction) {
+                    found_count += 1;
+                }
+            }
+        }
+
+        return found_count;
+    }
+
+    fn part_2(input: &Puzzle) -> usize {
+        let (rows, cols) = input.0.bounds();
+
+        let mut found_count = 0;
+
+        // Only search 1 from input borders because the 'A' needs to be surrounded by letters
+        for (row, col) in (1..rows - 1).cartesian_product(1..cols - 1) {
+            let position = Position::new(row, col);
+
+            if input.0[position] != 'A' {
+                continue;
+            }
+
+            // Clockwise, so rotating keeps the two M's next to each other
+            let mut offsets = Diagonal::ALL.map(Diagonal::offset);
+
+            for i in 0..4 {
+                offsets.rotate_right(i);
+
+                if input.this_A_is_surrounded_by(position, &offsets) {
+                    found_count += 1;
+
+                    // Can only be X-MAS in one direction
+                    continue;
+                }
+            }
+        }
+
+        return found_count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day04::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day04::part_1(&parsed), 18);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day04::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day04::part_2(&parsed), 9);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(Day04::parse("XMAS\nXM").err(), Some(GridError::RaggedLine { line: 2, text: "XM".to_string(), expected: 4, found: 2 }));
+        assert_eq!(Day04::parse("").err(), Some(GridError::Empty));
+    }
+}
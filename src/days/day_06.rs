@@ -0,0 +1,237 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{geometry::{Direction, Position}, grid::{Grid, GridError}, parse::Location, Solution};
+
+pub const EXAMPLE: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+pub struct Day06;
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Guard {
+    position: Position,
+    direction: Direction,
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    objects: Grid<bool>,
+    guard: Guard,
+    previous_states: HashSet::<Guard>,
+    is_done: bool,
+}
+
+impl Game {
+    fn is_object(&self, position: &Position) -> bool {
+        return self.objects[*position];
+    }
+
+    // Returns true if the new state of the guard is in the previous_states
+    fn move_guard(&mut self) -> bool {
+        // None once the guard walks off the grid
+        if let Some(new_position) = self.objects.offset(self.guard.position, self.guard.direction.offset()) {
+            if self.is_object(&new_position) {
+                self.guard.direction = self.guard.direction.turn_right();
+
+                let same_as_previous_state = self.previous_states.contains(&self.guard);
+
+                self.previous_states.insert(self.guard.clone());
+
+                return same_as_previous_state;
+            }
+
+            self.guard.position = new_position;
+
+            let same_as_previous_state = self.previous_states.contains(&self.guard);
+
+            self.previous_states.insert(self.guard.clone());
+
+            return same_as_previous_state;
+        }
+
+        self.is_done = true;
+
+        // If new_position is None, guard exited the board, which can't be a previous state.
+        return false;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Grid(GridError),
+    InvalidCharacter { location: Location, character: char },
+    MissingGuard,
+    SecondGuard { location: Location },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ParseError::Grid(error) => write!(f, "{error}"),
+            ParseError::InvalidCharacter { location, character } => write!(f, "{location}: invalid character {character:?}"),
+            ParseError::MissingGuard => write!(f, "no guard (one of ^, >, v, <) on the map"),
+            ParseError::SecondGuard { location } => write!(f, "{location}: there can only be one guard"),
+        };
+    }
+}
+
+impl From<GridError> for ParseError {
+    fn from(error: GridError) -> Self {
+        return ParseError::Grid(error);
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_input(input: &str) -> Result<Game, ParseError> {
+    let mut guard = None;
+
+    let objects = Grid::parse(input, |position, char| {
+        let direction = match char {
+            '#' => return Ok(true),
+            '.' => return Ok(false),
+            '^' => Direction::Up,
+            '>' => Direction::Right,
+            'v' => Direction::Down,
+            '<' => Direction::Left,
+            _ => return Err(ParseError::InvalidCharacter {
+                location: Location::at(position.row, position.col),
+                character: char,
+            }),
+        };
+
+        if guard.is_some() {
+            return Err(ParseError::SecondGuard { location: Location::at(position.row, position.col) });
+        }
+
+        guard = Some(Guard { position, direction });
+
+        return Ok(false);
+    })?;
+
+    let guard = guard.ok_or(ParseError::MissingGuard)?;
+
+    let mut previous_states = HashSet::new();
+
+    previous_states.insert(guard.clone());
+
+    return Ok(Game {
+        objects,
+        guard,
+        previous_states,
+        is_done: false,
+    });
+}
+
+impl Solution for Day06 {
+    type Parsed = Game;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Game, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part_1(game: &Game) -> usize {
+        let mut game = game.clone();
+
+        while !game.is_done {
+            game.move_guard();
+        }
+
+        // The guard passes some tiles in more than one direction, those only count once
+        let visited_positions = game.previous_states.iter()
+            .map(|guard| guard.position)
+            .collect::<HashSet<_>>();
+
+        return visited_positions.len();
+    }
+
+    fn part_2(game: &Game) -> usize {
+        // Play the game
+        // Every move, check:
+        // If putting an object in front of the guard at its current position and direction
+        // would put it onto a path if has already been on,
+        // that's a solution.
+        // However, it's nontrivial to find out if she'd get put on a path that she's already been on -
+        // you basically have to finish playing the game to check that.
+
+        // How impossible would brute force be?
+        // 16061 non-object spots in my input
+        // Sounds doable tbh
+
+        // It was pretty doable, went through 130 rows of the input at about 2 rows per second
+        // Fuck the answer is wrong
+
+        let mut count = 0;
+
+        for i in 0..game.objects.num_rows() {
+            for (j, is_object) in game.objects.row(i).enumerate() {
+                // The guard would notice an object dropped on top of her
+                if *is_object || Position::new(i, j) == game.guard.position {
+                    continue;
+                }
+
+                let mut test_game = game.clone();
+
+                // Sanity check
+                assert!(!test_game.objects[Position::new(i, j)]);
+
+                // Insert an object at this [i][j]
+                test_game.objects[Position::new(i, j)] = true;
+
+                while !test_game.is_done {
+                    if test_game.move_guard() {
+                        count += 1;
+
+                        break;
+                    }
+                }
+
+                // Game is done, guard left the arena,
+                // this was not a loop
+            }
+        }
+
+        return count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day06::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day06::part_1(&parsed), 41);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day06::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day06::part_2(&parsed), 6);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let ragged = ParseError::Grid(GridError::RaggedLine { line: 2, text: "..".to_string(), expected: 3, found: 2 });
+
+        assert_eq!(Day06::parse("..#\n.x^").err(), Some(ParseError::InvalidCharacter { location: Location::at(1, 1), character: 'x' }));
+        assert_eq!(Day06::parse("^.\n.>").err(), Some(ParseError::SecondGuard { location: Location::at(1, 1) }));
+        assert_eq!(Day06::parse("..\n.#").err(), Some(ParseError::MissingGuard));
+        assert_eq!(Day06::parse("^..\n..").err(), Some(ragged));
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt::Display;
+
+use crate::{arithmetic, parse::Location, Solution};
+
+pub const EXAMPLE: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+pub struct Day07;
+
+#[derive(Debug)]
+pub struct Equation (
+    u64,
+    Vec<u64>,
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingColon { line: usize, text: String },
+    InvalidNumber { location: Location, text: String },
+    NoNumbers { line: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ParseError::MissingColon { line, text } => write!(f, "line {line}: line doesn't have a colon ya dingus: {text:?}"),
+            ParseError::InvalidNumber { location, text } => write!(f, "{location}: invalid number {text:?}"),
+            ParseError::NoNumbers { line } => write!(f, "line {line}: equation doesn't have any numbers after the colon"),
+        };
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_number(line_index: usize, line: &str, number: &str) -> Result<u64, ParseError> {
+    return number.parse::<u64>().map_err(|_| ParseError::InvalidNumber {
+        location: Location::of(line_index, line, number),
+        text: number.to_string(),
+    });
+}
+
+fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    let mut result = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let (outcome, numbers) = line.split_once(':').ok_or_else(|| ParseError::MissingColon {
+            line: i + 1,
+            text: line.to_string(),
+        })?;
+
+        let outcome = parse_number(i, line, outcome)?;
+
+        let numbers = numbers.split_ascii_whitespace()
+            .map(|number| parse_number(i, line, number))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if numbers.is_empty() {
+            return Err(ParseError::NoNumbers { line: i + 1 });
+        }
+
+        result.push(Equation(outcome, numbers));
+    }
+
+    return Ok(result);
+}
+
+// Equations are one per line, so the index of an equation is its line too
+fn context(line_index: usize, expected_result: u64) -> impl Fn() -> String {
+    return move || format!("day 07, the equation for {expected_result} on line {}", line_index + 1);
+}
+
+fn equation_can_be_valid(line_index: usize, expected_result: u64, numbers: &[u64]) -> bool {
+    let context = context(line_index, expected_result);
+
+    let number_of_operators = (numbers.len() - 1) as u32;
+
+    // Mask is a binary representation of whether to choose '+' (0) or '*' (1)
+    for mask in 0..(2u32.pow(number_of_operators)) {
+        let mut result = numbers[0];
+
+        for (i, number) in numbers[1..].iter().enumerate() {
+            // get i-th bit from mask, starting with least significant bit
+            let operator_is_multiply = mask >> i & 1;
+
+            if operator_is_multiply == 1 {
+                result = arithmetic::mul(result, *number, &context);
+            } else {
+                result = arithmetic::add(result, *number, &context);
+            }
+        }
+
+        if result == expected_result {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+// 12 || 345 = 12345, by shifting left over as many digits as right has
+fn concatenate(left: u64, right: u64, context: impl Fn() -> String) -> u64 {
+    let digits = right.checked_ilog10().unwrap_or(0) + 1;
+    let shifted = arithmetic::mul(left, arithmetic::pow(10u64, digits, &context), &context);
+
+    return arithmetic::add(shifted, right, &context);
+}
+
+// I thought concatenation was always done as pre-processing,
+// but it's not.
+// 6 * 8 || 6 * 15 = 68 || 6 * 15 = 686 * 15 = 7290, valid in the test input
+// I interpreted that as 6 * 86 * 15 = 7740, invalid in the test input
+// fn all_possible_concatenations(numbers: &[usize]) -> Vec<Vec<usize>> {
+//     // println!("Finding all concatenations in {numbers:?}");
+
+//     // Base cases
+//     if numbers.is_empty() {
+//         // println!("Input empty, no combinations found");
+//         return vec![vec![]];
+//     }
+
+//     if numbers.len() == 1 {
+//         // println!("Input single digit, found {:?}", vec![vec![numbers[0]]]);
+//         return vec![vec![numbers[0]]];
+//     }
+
+//     let mut result = vec![];
+
+//     // General case 1: no concatenation
+//     let head_no_concat = vec![numbers[0]];
+
+//     let all_tails_no_concat = all_possible_concatenations(&numbers[1..]);
+
+//     for tail in all_tails_no_concat {
+//         let mut new_possibility = head_no_concat.clone();
+//         new_possibility.extend(tail);
+
+//         result.push(new_possibility);
+//     }
+
+//     // General case 2: concatenation
+//     let head_yes_concat = vec![concatenate(numbers[0], numbers[1])];
+
+//     let all_tails_yes_concat = all_possible_concatenations(&numbers[2..]);
+
+//     for tail in all_tails_yes_concat {
+//         let mut new_possibility = head_yes_concat.clone();
+//         new_possibility.extend(tail);
+
+//         result.push(new_possibility);
+//     }
+
+//     // println!("Found combinations: {result:?}");
+
+//     return result;
+// }
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+fn extend_vector_returning_value(vector: Vec<Operator>, extension: &[Operator]) -> Vec<Operator> {
+    let mut result = vector;
+
+    result.extend(extension);
+
+    return result;
+}
+
+fn build_all_operator_sequences(length: usize) -> Vec<Vec<Operator>> {
+    use Operator::*;
+
+    let mut result = vec![];
+
+    // Base case
+    if length == 0 {
+        return vec![vec![]];
+    }
+
+    let add_head = vec![Add];
+    let multiply_head = vec![Multiply];
+    let concatenate_head = vec![Concatenate];
+
+    // General case
+    let tails = build_all_operator_sequences(length - 1);
+
+    for tail in &tails {
+        result.push(extend_vector_returning_value(add_head.clone(), tail));
+        result.push(extend_vector_returning_value(multiply_head.clone(), tail));
+        result.push(extend_vector_returning_value(concatenate_head.clone(), tail));
+    }
+
+    return result;
+}
+
+impl Solution for Day07 {
+    type Parsed = Vec<Equation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part_1(equations: &Vec<Equation>) -> u64 {
+        let mut sum_of_results = 0;
+
+        for (i, &Equation(expected_result, ref numbers)) in equations.iter().enumerate() {
+            if equation_can_be_valid(i, expected_result, numbers) {
+                sum_of_results = arithmetic::add(sum_of_results, expected_result, || "day 07, the sum of the results".to_string());
+            }
+        }
+
+        return sum_of_results;
+    }
+
+    fn part_2(equations: &Vec<Equation>) -> u64 {
+        let mut sum_of_results = 0;
+
+        for (i, &Equation(expected_result, ref numbers)) in equations.iter().enumerate() {
+            let context = context(i, expected_result);
+
+            // Brute force solution but fuck it
+            for operator_sequence in build_all_operator_sequences(numbers.len() - 1) {
+                let mut result = numbers[0];
+
+                for (i, operator) in operator_sequence.iter().enumerate() {
+                    match operator {
+                        Operator::Add => { result = arithmetic::add(result, numbers[i + 1], &context); },
+                        Operator::Multiply => { result = arithmetic::mul(result, numbers[i + 1], &context); },
+                        Operator::Concatenate => {
+                            result = concatenate(result, numbers[i + 1], &context)
+                        },
+                    }
+                }
+
+                if result == expected_result {
+                    sum_of_results = arithmetic::add(sum_of_results, expected_result, || "day 07, the sum of the results".to_string());
+
+                    break;
+                }
+            }
+        }
+
+        return sum_of_results;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day07::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day07::part_1(&parsed), 3749);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day07::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day07::part_2(&parsed), 11387);
+    }
+
+    #[test]
+    fn concatenation() {
+        let context = || unreachable!();
+
+        assert_eq!(concatenate(12, 345, context), 12345);
+        assert_eq!(concatenate(7, 0, context), 70);
+        assert_eq!(concatenate(0, 10, context), 10);
+    }
+
+    // 10^19 has 20 digits, one more than any shift a u64 can hold. Debug builds have overflow checks, so
+    // without the feature it's still a panic, just without the context. `--release` without the feature wraps
+    #[test]
+    #[cfg(any(feature = "checked-arithmetic", debug_assertions))]
+    #[cfg_attr(feature = "checked-arithmetic", should_panic(expected = "overflow: 10 ^ 20 (concatenating)"))]
+    #[cfg_attr(not(feature = "checked-arithmetic"), should_panic(expected = "attempt to multiply with overflow"))]
+    fn concatenation_overflow_is_reported() {
+        concatenate(1, 10_000_000_000_000_000_000, || "concatenating".to_string());
+    }
+
+    #[test]
+    fn invalid_input() {
+        let colon = ParseError::MissingColon { line: 1, text: "190 10 19".to_string() };
+        let number = ParseError::InvalidNumber { location: Location::at(1, 8), text: "x9".to_string() };
+
+        assert_eq!(Day07::parse("190 10 19").err(), Some(colon));
+        assert_eq!(Day07::parse("29: 10 19\n190: 10 x9").err(), Some(number));
+        assert_eq!(Day07::parse("1: 1\n5:").err(), Some(ParseError::NoNumbers { line: 2 }));
+    }
+}
@@ -0,0 +1,201 @@
+use std::{collections::{HashMap, HashSet}, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::{geometry::{Offset, Position}, grid::{Grid, GridError}, parse::Location, Solution};
+
+pub const EXAMPLE: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+pub const EXAMPLE_2: &str = "T.........
+...T......
+.T........
+..........
+..........
+..........
+..........
+..........
+..........
+..........";
+
+pub struct Day08;
+
+#[derive(Debug)]
+pub struct City {
+    map: Grid<char>,
+    antennae: HashMap<char, Vec<Position>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Grid(GridError),
+    // Antennae are letters and digits, everything else is `.`
+    InvalidCharacter { location: Location, character: char },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ParseError::Grid(error) => write!(f, "{error}"),
+            ParseError::InvalidCharacter { location, character } => write!(f, "{location}: invalid character {character:?}"),
+        };
+    }
+}
+
+impl From<GridError> for ParseError {
+    fn from(error: GridError) -> Self {
+        return ParseError::Grid(error);
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_input(input: &str) -> Result<City, ParseError> {
+    let map = Grid::parse(input, |position, character| {
+        if character != '.' && !character.is_ascii_alphanumeric() {
+            return Err(ParseError::InvalidCharacter {
+                location: Location::at(position.row, position.col),
+                character,
+            });
+        }
+
+        return Ok(character);
+    })?;
+
+    let mut antennae = HashMap::new();
+
+    for (position, &character) in map.iter() {
+        if character == '.' {
+            continue;
+        }
+
+        antennae.entry(character)
+            .and_modify(|positions: &mut Vec<Position>| positions.push(position))
+            .or_insert(vec![position]);
+    }
+
+    return Ok(City {
+        map,
+        antennae,
+    });
+}
+
+fn all_resonant_locations(position: &Position, offset: &Offset, map: &Grid<char>) -> Vec<Position> {
+    let mut result = vec![];
+
+    // First positive multiples of offset
+    let mut i = 0;
+
+    while let Some(resonant_location) = map.offset(*position, *offset * i) {
+        result.push(resonant_location);
+
+        i += 1;
+    }
+
+    // Then negative offsets
+    let mut i = -1;
+
+    while let Some(resonant_location) = map.offset(*position, *offset * i) {
+        result.push(resonant_location);
+
+        i -= 1;
+    }
+
+    return result;
+}
+
+impl Solution for Day08 {
+    type Parsed = City;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<City, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part_1(city: &City) -> usize {
+        let mut resonant_locations = HashSet::<Position>::new();
+
+        // Which character it is, is irrelevant at this point
+        for positions in city.antennae.values() {
+            for pair in positions.iter().combinations(2) {
+                let position = pair[0];
+                let other_position = pair[1];
+
+                let offset = *other_position - *position;
+
+                if let Some(first_resonance) = city.map.offset(*position, -offset) {
+                    resonant_locations.insert(first_resonance);
+                }
+
+                if let Some(second_resonance) = city.map.offset(*other_position, offset) {
+                    resonant_locations.insert(second_resonance);
+                }
+            }
+        }
+
+        return resonant_locations.len();
+    }
+
+    fn part_2(city: &City) -> usize {
+        let mut resonant_locations = HashSet::<Position>::new();
+
+        // Which character it is, is irrelevant at this point
+        for positions in city.antennae.values() {
+            for pair in positions.iter().combinations(2) {
+                let position = pair[0];
+                let other_position = pair[1];
+
+                let offset = *other_position - *position;
+
+                resonant_locations.extend(all_resonant_locations(position, &offset, &city.map));
+            }
+        }
+
+        return resonant_locations.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day08::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day08::part_1(&parsed), 14);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day08::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day08::part_2(&parsed), 34);
+    }
+
+    // Only the T's, which shows how resonant harmonics reach along the whole line
+    #[test]
+    fn part_2_example_2() {
+        let parsed = Day08::parse(EXAMPLE_2).unwrap();
+
+        assert_eq!(Day08::part_2(&parsed), 9);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(Day08::parse("..a\n.#.").err(), Some(ParseError::InvalidCharacter { location: Location::at(1, 1), character: '#' }));
+        assert_eq!(Day08::parse("").err(), Some(ParseError::Grid(GridError::Empty)));
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt::Display;
+
+use crate::{arithmetic, parse::Location, Solution};
+
+pub const EXAMPLE: &str = "2333133121414131402";
+
+pub struct Day09;
+
+const EMPTY_SPACE: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+pub struct Disk(Vec<usize>);
+
+impl Disk {
+    // None if the disk is full
+    fn first_empty_block(&self) -> Option<usize> {
+        return self.0.iter().position(|block| *block == EMPTY_SPACE);
+    }
+
+    // None if the disk is empty
+    fn last_nonempty_block(&self) -> Option<usize> {
+        return self.0.iter().rposition(|block| *block != EMPTY_SPACE);
+    }
+
+    fn compress(&mut self) {
+        // Without free space or files there's nothing to move, the disk stays as it is
+        while let (Some(first_empty), Some(last_nonempty)) = (self.first_empty_block(), self.last_nonempty_block()) {
+            if first_empty > last_nonempty {
+                break;
+            }
+
+            self.0.swap(first_empty, last_nonempty);
+        }
+    }
+
+    fn checksum(&self) -> u64 {
+        let mut sum = 0;
+
+        for (i, block_content) in self.0.iter().enumerate() {
+            if *block_content != EMPTY_SPACE {
+                let context = || format!("day 09, the checksum of file {block_content} at block {i}");
+                let product = arithmetic::mul(i as u64, *block_content as u64, context);
+
+                sum = arithmetic::add(sum, product, context);
+            }
+        }
+
+        return sum;
+    }
+
+    fn empty_spaces(&self) -> Vec<(usize, usize)> {
+        let mut result = vec![];
+
+        let mut currently_on_empty_space = false;
+        let mut current_start_idx = 0;
+        let mut current_length = 0;
+
+        for (i, block_value) in self.0.iter().enumerate() {
+            if *block_value != EMPTY_SPACE {
+                // If going from empty to full
+                if currently_on_empty_space {
+                    result.push((current_start_idx, current_length));
+
+                    currently_on_empty_space = false;
+                    current_length = 0;
+                }
+
+                continue;
+            }
+
+            // If going from full blocks to empty
+            if !currently_on_empty_space {
+                current_start_idx = i;
+                currently_on_empty_space = true;
+            }
+
+            current_length += 1;
+        }
+
+        return result;
+    }
+
+    // Oh boy this isn't pretty
+    fn compress_no_fragmentation(&mut self) {
+        // Index to loop over the blocks, starting from the end
+        let mut index = self.0.len() - 1;
+        let mut handled_files = vec![];
+
+        loop {
+            if self.0[index] == EMPTY_SPACE || handled_files.contains(&self.0[index]) {
+                if index == 0 {
+                    break;
+                }
+
+                index -= 1;
+
+                continue;
+            }
+
+            // Find current file size
+            let current_file = self.0[index];
+            let current_file_length = self.0[..=index].iter().rev().take_while(|block| **block == current_file).count();
+
+            // Move to first empty space that fits it
+            for (start_idx, length) in self.empty_spaces() {
+                // Can't move it forward anymore
+                if start_idx >= index {
+                    break;
+                }
+
+                if length >= current_file_length {
+                    for i in 0..current_file_length {
+                        self.0.swap(start_idx + i, index - i);
+                    }
+
+                    break;
+                }
+            }
+
+            handled_files.push(current_file);
+
+            if index < current_file_length {
+                break;
+            }
+
+            index -= current_file_length;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidDigit { location: Location, character: char },
+    Empty,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ParseError::InvalidDigit { location, character } => write!(f, "{location}: expected a digit but found {character:?}"),
+            ParseError::Empty => write!(f, "the disk map doesn't contain any blocks"),
+        };
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn make_disk(input: &str) -> Result<Disk, ParseError> {
+    let mut result = vec![];
+
+    let mut currently_on_a_file = true;
+    let mut current_file_index = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        for (j, char) in line.chars().enumerate() {
+            let digit = char.to_digit(10).ok_or(ParseError::InvalidDigit {
+                location: Location::at(i, j),
+                character: char,
+            })?;
+
+            if currently_on_a_file {
+                for _ in 0..digit {
+                    result.push(current_file_index);
+                }
+
+                current_file_index += 1;
+            } else {
+                result.extend(std::iter::repeat_n(EMPTY_SPACE, digit as usize));
+            }
+
+            // Alternatingly, the disk map contains a file and a length of empty space
+            currently_on_a_file = !currently_on_a_file;
+        }
+    }
+
+    if result.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    return Ok(Disk(result));
+}
+
+impl Solution for Day09 {
+    type Parsed = Disk;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Disk, ParseError> {
+        return make_disk(input);
+    }
+
+    fn part_1(disk: &Disk) -> u64 {
+        let mut disk = disk.clone();
+
+        disk.compress();
+
+        return disk.checksum();
+    }
+
+    fn part_2(disk: &Disk) -> u64 {
+        let mut disk = disk.clone();
+
+        disk.compress_no_fragmentation();
+
+        return disk.checksum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day09::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day09::part_1(&parsed), 1928);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day09::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day09::part_2(&parsed), 2858);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(Day09::parse("12\n3x").err(), Some(ParseError::InvalidDigit { location: Location::at(1, 1), character: 'x' }));
+        assert_eq!(Day09::parse("00").err(), Some(ParseError::Empty));
+    }
+
+    // Without any free space nothing moves
+    #[test]
+    fn full_disk() {
+        for input in ["1", "101"] {
+            let parsed = Day09::parse(input).unwrap();
+
+            assert_eq!(Day09::part_1(&parsed), input.len() as u64 / 2);
+            assert_eq!(Day09::part_2(&parsed), input.len() as u64 / 2);
+        }
+    }
+}
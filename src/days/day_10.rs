@@ -0,0 +1,213 @@
+use std::{collections::{HashMap, HashSet, VecDeque}, fmt::Display};
+
+use crate::{geometry::Position, grid::{Grid, GridError}, parse::Location, Solution};
+
+pub const EXAMPLE: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+pub struct Day10;
+
+pub struct Map(Grid<usize>);
+
+impl Map {
+    fn index(&self, position: &Position) -> usize {
+        return self.0[*position];
+    }
+
+    fn num_trails_from(&self, starting_position: &Position) -> usize {
+        let mut valid_trails_backlog = VecDeque::new();
+
+        let starting_trail = Trail(vec![*starting_position]);
+
+        valid_trails_backlog.push_back(starting_trail);
+
+        let mut all_valid_trails = HashSet::<(Position, Position)>::new();
+
+        while let Some(trail) = valid_trails_backlog.pop_front() {
+            if self.index(trail.tail()) == 9 {
+                all_valid_trails.insert((*starting_position, *trail.tail()));
+
+                continue;
+            }
+
+            for new_position in trail.possible_next_positions(self) {
+                valid_trails_backlog.push_back(trail.extend(new_position));
+            }
+        }
+
+        return all_valid_trails.len();
+    }
+
+    // Counts different paths from start to finish as unique trails rather than the same trail
+    fn num_unique_trails_from(&self, starting_position: &Position) -> usize {
+        let mut valid_trails_backlog = VecDeque::new();
+
+        let starting_trail = Trail(vec![*starting_position]);
+
+        valid_trails_backlog.push_back(starting_trail);
+
+        let mut count = 0;
+
+        while let Some(trail) = valid_trails_backlog.pop_front() {
+            if self.index(trail.tail()) == 9 {
+                count += 1;
+
+                continue;
+            }
+
+            for new_position in trail.possible_next_positions(self) {
+                valid_trails_backlog.push_back(trail.extend(new_position));
+            }
+        }
+
+        return count;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Trail(Vec<Position>);
+
+impl Trail {
+    fn tail(&self) -> &Position {
+        return self.0.last().unwrap();
+    }
+
+    fn extend(&self, new_tail: Position) -> Self {
+        let mut result = self.clone();
+
+        result.0.push(new_tail);
+
+        return result;
+    }
+
+    fn possible_next_positions(&self, map: &Map) -> Vec<Position> {
+        let mut result = vec![];
+
+        let current_value = map.index(self.tail());
+
+        for new_position in map.0.neighbours_4(*self.tail()) {
+            if self.0.contains(&new_position) {
+                continue;
+            }
+
+            if map.index(&new_position) == current_value + 1 {
+                result.push(new_position);
+            }
+        }
+
+        return result;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Grid(GridError),
+    InvalidHeight { location: Location, character: char },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ParseError::Grid(error) => write!(f, "{error}"),
+            ParseError::InvalidHeight { location, character } => {
+                write!(f, "{location}: expected a height from 0 to 9 but found {character:?}")
+            },
+        };
+    }
+}
+
+impl From<GridError> for ParseError {
+    fn from(error: GridError) -> Self {
+        return ParseError::Grid(error);
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let map = Grid::parse(input, |position, character| -> Result<usize, ParseError> {
+        // For while I'm runnig the incomplete test inputs
+        if character == '.' {
+            return Ok(usize::MAX);
+        }
+
+        let height = character.to_digit(10).ok_or(ParseError::InvalidHeight {
+            location: Location::at(position.row, position.col),
+            character,
+        })?;
+
+        return Ok(height as usize);
+    })?;
+
+    return Ok(Map(map));
+}
+
+impl Solution for Day10 {
+    type Parsed = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part_1(map: &Map) -> usize {
+        // Maps a starting position to the number of hike trails from there
+        let mut hike_trails = HashMap::<Position, usize>::new();
+
+        for starting_position in map.0.positions_of(&0) {
+            let num_trails = map.num_trails_from(&starting_position);
+
+            hike_trails.insert(starting_position, num_trails);
+        }
+
+        return hike_trails.values().sum();
+    }
+
+    fn part_2(map: &Map) -> usize {
+        // Maps a starting position to the number of hike trails from there
+        let mut hike_trails = HashMap::<Position, usize>::new();
+
+        for starting_position in map.0.positions_of(&0) {
+            let num_trails = map.num_unique_trails_from(&starting_position);
+
+            hike_trails.insert(starting_position, num_trails);
+        }
+
+        return hike_trails.values().sum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day10::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day10::part_1(&parsed), 36);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day10::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day10::part_2(&parsed), 81);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let ragged = ParseError::Grid(GridError::RaggedLine { line: 2, text: "2".to_string(), expected: 2, found: 1 });
+
+        assert_eq!(Day10::parse("01\n2?").err(), Some(ParseError::InvalidHeight { location: Location::at(1, 1), character: '?' }));
+        assert_eq!(Day10::parse("01\n2").err(), Some(ragged));
+    }
+}
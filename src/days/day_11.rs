@@ -0,0 +1,164 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{arithmetic, parse::Location, Solution};
+
+pub const EXAMPLE: &str = "125 17";
+
+pub struct Day11;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidNumber { location: Location, text: String },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ParseError::InvalidNumber { location, text } => write!(f, "{location}: invalid number {text:?}"),
+        };
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut result = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        for number in line.split_ascii_whitespace() {
+            let number = number.parse().map_err(|_| ParseError::InvalidNumber {
+                location: Location::of(i, line, number),
+                text: number.to_string(),
+            })?;
+
+            result.push(number);
+        }
+    }
+
+    return Ok(result);
+}
+
+fn blink(state: &[u64]) -> Vec<u64> {
+    let mut result = vec![];
+
+    for number in state {
+        match *number {
+            0 => result.push(1),
+            n if n.to_string().len() % 2 == 0 => {
+                let n = n.to_string();
+
+                let (left, right) = n.split_at(n.len() / 2);
+
+                result.push(left.parse().unwrap());
+                result.push(right.parse().unwrap());
+            },
+            other => result.push(arithmetic::mul(other, 2024, || format!("day 11, blinking at stone {other}"))),
+        }
+    }
+
+    return result;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Rock(u64);
+
+impl Rock {
+    fn blink(&self) -> Vec<Rock> {
+        return match self.0 {
+            0 => vec![Rock(1)],
+            n if n.to_string().len() % 2 == 0 => {
+                let n = n.to_string();
+
+                let (left, right) = n.split_at(n.len() / 2);
+
+                vec![Rock(left.parse().unwrap()), Rock(right.parse().unwrap())]
+            },
+            other => vec![Rock(arithmetic::mul(other, 2024, || format!("day 11, blinking at stone {other}")))],
+        };
+    }
+
+    // u128 because the count grows exponentially with the blinks, unlike the numbers on the stones
+    fn total_descendant_rocks(&self, num_blinks: usize, all_rocks: &mut HashMap<(Rock, usize), u128>) -> u128 {
+        // Base case 1: no blinks remaining
+        if num_blinks == 0 {
+            return 1;
+        }
+
+        let mut total_descendants = 0;
+
+        // General case: value is the sum of the values of the descendant rocks after one blink
+        for rock in self.blink() {
+            // Shortcut: we've already processed this rock at this depth
+            if let Some(descendant_count) = all_rocks.get(&(rock, num_blinks - 1)) {
+                total_descendants += descendant_count;
+
+                continue;
+            }
+
+            let descendant_count = rock.total_descendant_rocks(num_blinks - 1, all_rocks);
+
+            total_descendants += descendant_count;
+
+            // num_blinks == 0 is the base case anyways, only benefit in inserting it is one function call
+            // But then for debugging a cleaner output is nicer
+            if num_blinks - 1 != 0 {
+                all_rocks.insert((rock, num_blinks - 1), descendant_count);
+            }
+        }
+
+        return total_descendants;
+    }
+}
+
+impl Solution for Day11 {
+    type Parsed = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = u128;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part_1(rocks: &Vec<u64>) -> usize {
+        let mut rocks = rocks.clone();
+
+        for _ in 0..25 {
+            rocks = blink(&rocks);
+        }
+
+        return rocks.len();
+    }
+
+    fn part_2(rocks: &Vec<u64>) -> u128 {
+        let mut all_rocks = HashMap::new();
+        let mut total_count = 0;
+
+        for rock in rocks {
+            total_count += Rock(*rock).total_descendant_rocks(75, &mut all_rocks)
+        }
+
+        return total_count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day11::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day11::part_1(&parsed), 55312);
+    }
+
+    // The puzzle doesn't give an answer for 75 blinks on the example, the real input test covers part 2
+
+    #[test]
+    fn invalid_input() {
+        let number = ParseError::InvalidNumber { location: Location::at(1, 1), text: "3a".to_string() };
+
+        assert_eq!(Day11::parse("125 17\n 3a").err(), Some(number));
+    }
+}
@@ -0,0 +1,370 @@
+use std::collections::HashSet;
+
+use crate::{geometry::{Direction, Offset, Position}, grid::{Grid, GridError}, Solution};
+
+pub const EXAMPLE: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+pub struct Day12;
+
+#[derive(Debug)]
+struct Region {
+    character: char,
+    positions: Vec<Position>,
+}
+
+pub struct Garden {
+    plots: Grid<char>,
+    regions: Vec<Region>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Fence {
+    row: usize,
+    col: usize,
+    is_horizontal: bool,
+    // Not horizontal/vertical, but for horizontal it's up or down and for vertical it's left/right
+    // orientation is true if 'outside' of fence is down or right, false if outside is up or left
+    orientation: bool,
+}
+
+impl Fence {
+    fn is_connecting_fence_horizontally(&self, other_fence: &Fence) -> bool {
+        return self.row == other_fence.row
+            && (self.col + 1 == other_fence.col || self.col == other_fence.col + 1);
+    }
+
+    fn is_connecting_fence_vertically(&self, other_fence: &Fence) -> bool {
+        return self.col == other_fence.col
+            && (self.row + 1 == other_fence.row || self.row == other_fence.row + 1);
+    }
+
+    fn belongs_to_side(&self, side: &[Fence]) -> bool {
+        if self.is_horizontal != side.first().unwrap().is_horizontal {
+            return false;
+        }
+
+        if self.orientation != side.first().unwrap().orientation {
+            return false;
+        }
+
+        for fence in side {
+            match self.is_horizontal {
+                true => {
+                    if self.is_connecting_fence_horizontally(fence) {
+                        return true;
+                    }
+                },
+                false => {
+                    if self.is_connecting_fence_vertically(fence) {
+                        return true;
+                    }
+                },
+            }
+        }
+
+        return false;
+    }
+}
+
+impl Region {
+    fn value(&self, plots: &Grid<char>) -> usize {
+        return self.perimeter_size(plots) * self.area();
+    }
+
+    fn perimeter_size(&self, plots: &Grid<char>) -> usize {
+        let mut perimeter = 0;
+
+        for position in &self.positions {
+            let mut number_of_neighbours_that_are_in_same_region = 0;
+
+            for neighbour in plots.neighbours_4(*position) {
+                // If neighbour is in this region, we don't add a fence
+                if self.positions.contains(&neighbour) {
+                    number_of_neighbours_that_are_in_same_region += 1;
+                }
+            }
+
+            // For each neighbour that is in the region, this position has one fewer fence
+            perimeter += 4 - number_of_neighbours_that_are_in_same_region;
+        }
+
+        return perimeter;
+    }
+
+    fn area(&self) -> usize {
+        return self.positions.len();
+    }
+
+    fn discount_value(&self, garden: &Garden) -> usize {
+        return self.number_of_sides(garden) * self.area();
+    }
+
+    fn all_fences(&self, garden: &Garden) -> HashSet<Fence> {
+        // (x, y) means a fence is above row x and on row y, so x runs from 0 to num_rows inclusive and y from 0 to num_cols exclusive
+        // Likewise for vertical
+        let mut fences = HashSet::<Fence>::new();
+        // let mut vertical_fences = HashSet::<Fence>::new();
+
+        for position in &self.positions {
+            for horizontal_direction in Direction::ALL.into_iter().filter(|direction| direction.is_horizontal()) {
+                // Unbounded, so it can be just outside of the garden
+                let position_plus_offset = Offset::from(*position) + horizontal_direction.offset();
+
+                let potential_fence_to_insert = if horizontal_direction == Direction::Left {
+                    Fence {
+                        // +1 will make it always >= 0 so can cast to usize np
+                        // +1 because of the way we enumerate fences
+                        row: position_plus_offset.row as usize,
+                        col: (position_plus_offset.col + 1) as usize,
+                        is_horizontal: false,
+                        orientation: false,
+                    }
+                } else {
+                    Fence {
+                        row: position_plus_offset.row as usize,
+                        col: position_plus_offset.col as usize,
+                        is_horizontal: false,
+                        orientation: true,
+                    }
+                };
+
+                // If out of bounds, always add a fence
+                // Don't have to check row value because it's a horizontal offset
+                if position_plus_offset.col < 0 || position_plus_offset.col >= garden.plots.num_cols() as isize {
+                    fences.insert(potential_fence_to_insert);
+
+                    continue;
+                }
+
+                // Man rust is amazing
+                if let Some(character) = garden.plots.get(Position::new(position_plus_offset.row as usize, position_plus_offset.col as usize)) {
+                    // Same region, no fence here
+                    if *character == self.character {
+                        continue;
+                    }
+                }
+
+                // In that direction there is either nothing or a different region, so we put a fence
+                fences.insert(potential_fence_to_insert);
+            }
+
+            // You ever heard of the DRY principle? me neither
+            for vertical_direction in Direction::ALL.into_iter().filter(|direction| !direction.is_horizontal()) {
+                // Unbounded, so it can be just outside of the garden
+                let position_plus_offset = Offset::from(*position) + vertical_direction.offset();
+
+                let potential_fence_to_insert = if vertical_direction == Direction::Up {
+                    Fence {
+                        // +1 will make it always >= 0 so can cast to usize np
+                        // +1 because of the way we enumerate fences
+                        row: (position_plus_offset.row + 1) as usize,
+                        col: position_plus_offset.col as usize,
+                        is_horizontal: true,
+                        orientation: false,
+                    }
+                } else {
+                    Fence {
+                        row: position_plus_offset.row as usize,
+                        col: position_plus_offset.col as usize,
+                        is_horizontal: true,
+                        orientation: true,
+                    }
+                };
+
+                // If out of bounds, always add a fence
+                // Don't have to check row value because it's a horizontal offset
+                if position_plus_offset.row < 0 || position_plus_offset.row >= garden.plots.num_rows() as isize {
+                    fences.insert(potential_fence_to_insert);
+
+                    continue;
+                }
+
+                // Man rust is amazing
+                if let Some(character) = garden.plots.get(Position::new(position_plus_offset.row as usize, position_plus_offset.col as usize)) {
+                    // Same region, no fence here
+                    if *character == self.character {
+                        continue;
+                    }
+                }
+
+                // In that direction there is either nothing or a different region, so we put a fence
+                fences.insert(potential_fence_to_insert);
+            }
+        }
+
+        return fences;
+    }
+
+    fn number_of_sides(&self, garden: &Garden) -> usize {
+        let fences = self.all_fences(garden);
+
+        let mut fences = fences.into_iter().collect::<Vec<_>>();
+
+        let mut total_sides = 0;
+
+        while let Some(starting_fence) = fences.pop() {
+            // Fences that potentially belong to the same side
+            let mut other_fences = fences.clone()
+                .into_iter()
+                .filter(|other_fence| other_fence.is_horizontal == starting_fence.is_horizontal && other_fence.orientation == starting_fence.orientation)
+                .collect::<Vec<_>>();
+
+            let mut current_side = vec![starting_fence];
+
+            while !other_fences.is_empty() {
+                let mut found_neighbour_in_current_side = false;
+
+                // Check each of the potential fences to see if it belongs to the current side
+                for other_fence in &other_fences {
+                    if other_fence.belongs_to_side(&current_side) {
+                        current_side.push(other_fence.clone());
+
+                        found_neighbour_in_current_side = true;
+
+                        break;
+                    }
+                }
+
+                // If no new fence belongs to the current side, it hasn't changed and we're done searching
+                if !found_neighbour_in_current_side {
+                    break;
+                }
+
+                remove_fences_in_current_side(&mut other_fences, &current_side);
+            }
+
+            remove_fences_in_current_side(&mut fences, &current_side);
+
+            total_sides += 1;
+        }
+
+        return total_sides;
+    }
+}
+
+fn remove_fences_in_current_side(all_fences: &mut Vec<Fence>, current_side: &[Fence]) {
+    // Remove all fences in current side to prevent double counting
+    // Iterate in reverse because ykno index out of bounds and stuff otherwise,
+    // i.e. elements >i get shifted towards 0 when element i gets removed
+    for i in (0..all_fences.len()).rev() {
+        if current_side.contains(&all_fences[i]) {
+            all_fences.remove(i);
+        }
+    }
+}
+
+fn construct_region(region: &mut Region, starting_position: Position, plots: &Grid<char>) {
+    region.positions.push(starting_position);
+
+    for neighbour in plots.neighbours_4(starting_position) {
+        // If different region
+        if plots[neighbour] != region.character {
+            continue;
+        }
+
+        // If this region already contains this neighbour
+        if region.positions.contains(&neighbour) {
+            continue;
+        }
+
+        construct_region(region, neighbour, plots);
+    }
+}
+
+fn parse_input(input: &str) -> Result<Garden, GridError> {
+    let plots = Grid::parse_chars(input)?;
+
+    let mut positions_to_check: Vec<Position> = plots.positions().collect();
+
+    let mut regions = vec![];
+
+    // While there is an unprocessed region left
+    // The Some(position) is single position whose entire region will be handled in the iteration
+    while let Some(position) = positions_to_check.first() {
+        let character = plots[*position];
+
+        let mut region = Region {
+            character,
+            positions: vec![],
+        };
+
+        construct_region(&mut region, *position, &plots);
+
+        // Remove the regions area from the positions to check
+        // Very inefficient, much nice
+        for position_in_region in region.positions.iter() {
+            if let Some(index) = positions_to_check.iter().position(|position_to_check| position_to_check == position_in_region) {
+                positions_to_check.remove(index);
+
+                continue;
+            }
+
+            // This shouldn't happen, a region can't contain a position that is already part of another region
+            panic!("Don't be stupid");
+        }
+
+        regions.push(region);
+    }
+
+    return Ok(Garden {
+        plots,
+        regions,
+    });
+}
+
+impl Solution for Day12 {
+    type Parsed = Garden;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = GridError;
+
+    fn parse(input: &str) -> Result<Garden, GridError> {
+        return parse_input(input);
+    }
+
+    fn part_1(garden: &Garden) -> usize {
+        return garden.regions.iter()
+            .map(|region| region.value(&garden.plots))
+            .sum();
+    }
+
+    fn part_2(garden: &Garden) -> usize {
+        return garden.regions.iter()
+            .map(|region| region.discount_value(garden))
+            .sum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let parsed = Day12::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day12::part_1(&parsed), 1930);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = Day12::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day12::part_2(&parsed), 1206);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(Day12::parse("AA\nA").err(), Some(GridError::RaggedLine { line: 2, text: "A".to_string(), expected: 2, found: 1 }));
+        assert_eq!(Day12::parse("").err(), Some(GridError::Empty));
+    }
+}
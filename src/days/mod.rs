@@ -0,0 +1,46 @@
+use std::error::Error;
+
+use crate::{run, Run};
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+
+// Parses the inputs and solves the given parts, see `run`
+pub type RunFn = fn((&str, &str), &[usize], bool) -> Result<Run, Box<dyn Error>>;
+
+pub struct Day {
+    pub number: usize,
+    pub run: RunFn,
+    // What `--example` runs part 1 and part 2 on, the same one unless the puzzle has a separate example
+    // for part 2
+    pub examples: (&'static str, &'static str),
+}
+
+pub const ALL: &[Day] = &[
+    Day { number: 1, run: run::<day_01::Day01>, examples: (day_01::EXAMPLE, day_01::EXAMPLE) },
+    Day { number: 2, run: run::<day_02::Day02>, examples: (day_02::EXAMPLE, day_02::EXAMPLE) },
+    Day { number: 3, run: run::<day_03::Day03>, examples: (day_03::EXAMPLE, day_03::EXAMPLE_2) },
+    Day { number: 4, run: run::<day_04::Day04>, examples: (day_04::EXAMPLE, day_04::EXAMPLE) },
+    Day { number: 5, run: run::<day_05::Day05>, examples: (day_05::EXAMPLE, day_05::EXAMPLE) },
+    Day { number: 6, run: run::<day_06::Day06>, examples: (day_06::EXAMPLE, day_06::EXAMPLE) },
+    Day { number: 7, run: run::<day_07::Day07>, examples: (day_07::EXAMPLE, day_07::EXAMPLE) },
+    Day { number: 8, run: run::<day_08::Day08>, examples: (day_08::EXAMPLE, day_08::EXAMPLE) },
+    Day { number: 9, run: run::<day_09::Day09>, examples: (day_09::EXAMPLE, day_09::EXAMPLE) },
+    Day { number: 10, run: run::<day_10::Day10>, examples: (day_10::EXAMPLE, day_10::EXAMPLE) },
+    Day { number: 11, run: run::<day_11::Day11>, examples: (day_11::EXAMPLE, day_11::EXAMPLE) },
+    Day { number: 12, run: run::<day_12::Day12>, examples: (day_12::EXAMPLE, day_12::EXAMPLE) },
+];
+
+pub fn get(number: usize) -> Option<&'static Day> {
+    return ALL.iter().find(|day| day.number == number);
+}
@@ -0,0 +1,328 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+// A point on a grid, rows go down and columns go right starting at 0 in the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+// Signed counterpart of Position, either a vector between two positions or a point that may lie off the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Offset {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Self {
+        return Position { row, col };
+    }
+
+    // Returns None if the result would have a negative coordinate
+    pub fn checked_add(self, offset: Offset) -> Option<Position> {
+        return Some(Position {
+            row: self.row.checked_add_signed(offset.row)?,
+            col: self.col.checked_add_signed(offset.col)?,
+        });
+    }
+
+    // Returns None if the result falls outside of bounds (rows, columns), exclusive
+    pub fn add_bounded(self, offset: Offset, bounds: (usize, usize)) -> Option<Position> {
+        let result = self.checked_add(offset)?;
+
+        if result.row >= bounds.0 || result.col >= bounds.1 {
+            return None;
+        }
+
+        return Some(result);
+    }
+
+    pub fn manhattan_distance(self, other: Position) -> usize {
+        return (other - self).manhattan_length();
+    }
+
+    pub fn chebyshev_distance(self, other: Position) -> usize {
+        return (other - self).chebyshev_length();
+    }
+}
+
+impl Offset {
+    pub const fn new(row: isize, col: isize) -> Self {
+        return Offset { row, col };
+    }
+
+    pub fn manhattan_length(self) -> usize {
+        return self.row.unsigned_abs() + self.col.unsigned_abs();
+    }
+
+    // Number of king moves, i.e. diagonal steps count as one
+    pub fn chebyshev_length(self) -> usize {
+        return self.row.unsigned_abs().max(self.col.unsigned_abs());
+    }
+
+    // Clockwise as seen on screen, so up turns into right
+    pub fn turn_right(self) -> Self {
+        return Offset::new(self.col, -self.row);
+    }
+
+    pub fn turn_left(self) -> Self {
+        return Offset::new(-self.col, self.row);
+    }
+
+    pub fn reverse(self) -> Self {
+        return -self;
+    }
+}
+
+impl From<Position> for Offset {
+    fn from(position: Position) -> Self {
+        return Offset::new(position.row as isize, position.col as isize);
+    }
+}
+
+impl Add for Offset {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        return Offset::new(self.row + rhs.row, self.col + rhs.col);
+    }
+}
+
+impl AddAssign for Offset {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Offset {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        return Offset::new(self.row - rhs.row, self.col - rhs.col);
+    }
+}
+
+// The vector that takes you from rhs to self
+impl Sub for Position {
+    type Output = Offset;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        return Offset::from(self) - Offset::from(rhs);
+    }
+}
+
+impl Neg for Offset {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        return Offset::new(-self.row, -self.col);
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        return Offset::new(self.row * rhs, self.col * rhs);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise, starting at the top
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub const fn offset(self) -> Offset {
+        return match self {
+            Direction::Up => Offset::new(-1, 0),
+            Direction::Right => Offset::new(0, 1),
+            Direction::Down => Offset::new(1, 0),
+            Direction::Left => Offset::new(0, -1),
+        };
+    }
+
+    pub fn turn_right(self) -> Self {
+        use Direction::*;
+
+        return match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        };
+    }
+
+    pub fn turn_left(self) -> Self {
+        return self.turn_right().turn_right().turn_right();
+    }
+
+    pub fn reverse(self) -> Self {
+        return self.turn_right().turn_right();
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        return matches!(self, Direction::Left | Direction::Right);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diagonal {
+    UpRight,
+    DownRight,
+    DownLeft,
+    UpLeft,
+}
+
+impl Diagonal {
+    // Clockwise, starting at the top right
+    pub const ALL: [Diagonal; 4] = [Diagonal::UpRight, Diagonal::DownRight, Diagonal::DownLeft, Diagonal::UpLeft];
+
+    pub const fn offset(self) -> Offset {
+        return match self {
+            Diagonal::UpRight => Offset::new(-1, 1),
+            Diagonal::DownRight => Offset::new(1, 1),
+            Diagonal::DownLeft => Offset::new(1, -1),
+            Diagonal::UpLeft => Offset::new(-1, -1),
+        };
+    }
+
+    pub fn turn_right(self) -> Self {
+        use Diagonal::*;
+
+        return match self {
+            UpRight => DownRight,
+            DownRight => DownLeft,
+            DownLeft => UpLeft,
+            UpLeft => UpRight,
+        };
+    }
+
+    pub fn turn_left(self) -> Self {
+        return self.turn_right().turn_right().turn_right();
+    }
+
+    pub fn reverse(self) -> Self {
+        return self.turn_right().turn_right();
+    }
+}
+
+pub const FOUR_OFFSETS: [Offset; 4] = [
+    Direction::Up.offset(),
+    Direction::Right.offset(),
+    Direction::Down.offset(),
+    Direction::Left.offset(),
+];
+
+// All four directions followed by all four diagonals
+pub const EIGHT_OFFSETS: [Offset; 8] = [
+    Direction::Up.offset(),
+    Direction::Right.offset(),
+    Direction::Down.offset(),
+    Direction::Left.offset(),
+    Diagonal::UpRight.offset(),
+    Diagonal::DownRight.offset(),
+    Diagonal::DownLeft.offset(),
+    Diagonal::UpLeft.offset(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_right().turn_right().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left().turn_left().turn_left(), direction);
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+
+            // The offsets turn the same way as the directions
+            assert_eq!(direction.offset().turn_right(), direction.turn_right().offset());
+            assert_eq!(direction.offset().turn_left(), direction.turn_left().offset());
+            assert_eq!(direction.offset().reverse(), direction.reverse().offset());
+        }
+
+        for diagonal in Diagonal::ALL {
+            assert_eq!(diagonal.turn_right().turn_right().turn_right().turn_right(), diagonal);
+            assert_eq!(diagonal.turn_left().turn_left().turn_left().turn_left(), diagonal);
+            assert_eq!(diagonal.turn_left().turn_right(), diagonal);
+            assert_eq!(diagonal.reverse().reverse(), diagonal);
+
+            assert_eq!(diagonal.offset().turn_right(), diagonal.turn_right().offset());
+            assert_eq!(diagonal.offset().turn_left(), diagonal.turn_left().offset());
+            assert_eq!(diagonal.offset().reverse(), diagonal.reverse().offset());
+        }
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Diagonal::UpRight.turn_right(), Diagonal::DownRight);
+        assert_eq!(Diagonal::UpRight.turn_left(), Diagonal::UpLeft);
+        assert_eq!(Diagonal::UpRight.reverse(), Diagonal::DownLeft);
+
+        let offset = Offset::new(2, -5);
+
+        assert_eq!(offset.turn_right().turn_right().turn_right().turn_right(), offset);
+        assert_eq!(offset.turn_right(), Offset::new(-5, -2));
+        assert_eq!(offset.turn_left(), Offset::new(5, 2));
+        assert_eq!(offset.reverse(), Offset::new(-2, 5));
+
+        assert!(Direction::Left.is_horizontal());
+        assert!(!Direction::Up.is_horizontal());
+    }
+
+    #[test]
+    fn distances() {
+        let a = Position::new(1, 7);
+        let b = Position::new(4, 2);
+
+        assert_eq!(b - a, Offset::new(3, -5));
+        assert_eq!(a.manhattan_distance(b), 8);
+        assert_eq!(b.manhattan_distance(a), 8);
+        assert_eq!(a.chebyshev_distance(b), 5);
+        assert_eq!(b.chebyshev_distance(a), 5);
+        assert_eq!(a.manhattan_distance(a), 0);
+        assert_eq!(a.chebyshev_distance(a), 0);
+
+        assert_eq!(Offset::new(-3, -3).manhattan_length(), 6);
+        assert_eq!(Offset::new(-3, -3).chebyshev_length(), 3);
+        assert_eq!(Offset::new(0, -4).chebyshev_length(), 4);
+
+        assert_eq!(Offset::new(1, 2) * 3 + Offset::new(-1, 0) - Offset::new(0, 1), Offset::new(2, 5));
+    }
+
+    #[test]
+    fn bounded_addition() {
+        let origin = Position::new(0, 0);
+
+        assert_eq!(origin.checked_add(Offset::new(0, 0)), Some(origin));
+        assert_eq!(origin.checked_add(Offset::new(-1, 0)), None);
+        assert_eq!(origin.checked_add(Offset::new(0, -1)), None);
+        assert_eq!(Position::new(3, 3).checked_add(Offset::new(-3, -3)), Some(origin));
+
+        // At the top end of usize rather than wrapping around
+        assert_eq!(Position::new(usize::MAX, 0).checked_add(Offset::new(1, 0)), None);
+        assert_eq!(Position::new(usize::MAX - 1, 0).checked_add(Offset::new(1, 0)), Some(Position::new(usize::MAX, 0)));
+        assert_eq!(Position::new(0, usize::MAX).checked_add(Offset::new(0, isize::MAX)), None);
+
+        // Bounds are exclusive, the last row and column are inside
+        let bounds = (3, 5);
+
+        assert_eq!(origin.add_bounded(Offset::new(2, 4), bounds), Some(Position::new(2, 4)));
+        assert_eq!(origin.add_bounded(Offset::new(3, 0), bounds), None);
+        assert_eq!(origin.add_bounded(Offset::new(0, 5), bounds), None);
+        assert_eq!(origin.add_bounded(Offset::new(-1, 0), bounds), None);
+        assert_eq!(Position::new(2, 4).add_bounded(Offset::new(-2, -4), bounds), Some(origin));
+        assert_eq!(Position::new(2, 4).add_bounded(Direction::Right.offset(), bounds), None);
+        assert_eq!(Position::new(usize::MAX, 0).add_bounded(Direction::Down.offset(), bounds), None);
+    }
+}
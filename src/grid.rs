@@ -0,0 +1,403 @@
+use std::{fmt::Display, ops::{Index, IndexMut}};
+
+use crate::geometry::{Diagonal, Offset, Position, EIGHT_OFFSETS, FOUR_OFFSETS};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    RaggedLine { line: usize, text: String, expected: usize, found: usize },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            GridError::Empty => write!(f, "the grid is empty"),
+            GridError::RaggedLine { line, text, expected, found } => {
+                write!(f, "line {line}: expected {expected} cells like the first line but found {found} in {text:?}")
+            },
+        };
+    }
+}
+
+impl std::error::Error for GridError {}
+
+// A rectangular grid, stored row after row in one flat vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    num_rows: usize,
+    num_cols: usize,
+}
+
+impl<T> Grid<T> {
+    // Parses one line per row, turning every character into a cell with `mapper`.
+    // The day's own error type has to be able to hold the structural errors, hence the `From<GridError>`
+    pub fn parse<E: From<GridError>>(input: &str, mut mapper: impl FnMut(Position, char) -> Result<T, E>) -> Result<Self, E> {
+        let mut cells = vec![];
+        let mut num_rows = 0;
+        let mut num_cols = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let mut current_length = 0;
+
+            for (j, character) in line.chars().enumerate() {
+                cells.push(mapper(Position::new(i, j), character)?);
+
+                current_length += 1;
+            }
+
+            let expected = *num_cols.get_or_insert(current_length);
+
+            if current_length != expected {
+                return Err(GridError::RaggedLine {
+                    line: i + 1,
+                    text: line.to_string(),
+                    expected,
+                    found: current_length,
+                }.into());
+            }
+
+            num_rows += 1;
+        }
+
+        let num_cols = match num_cols {
+            Some(num_cols) if num_cols > 0 => num_cols,
+            _ => return Err(GridError::Empty.into()),
+        };
+
+        return Ok(Grid {
+            cells,
+            num_rows,
+            num_cols,
+        });
+    }
+
+    pub fn num_rows(&self) -> usize {
+        return self.num_rows;
+    }
+
+    pub fn num_cols(&self) -> usize {
+        return self.num_cols;
+    }
+
+    // (rows, columns)
+    pub fn bounds(&self) -> (usize, usize) {
+        return (self.num_rows, self.num_cols);
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        return position.row < self.num_rows && position.col < self.num_cols;
+    }
+
+    fn assert_contains(&self, position: Position) {
+        assert!(self.contains(position), "Position {position:?} out of bounds for a grid of size {:?}", self.bounds());
+    }
+
+    fn flat_index(&self, position: Position) -> Option<usize> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        return Some(position.row * self.num_cols + position.col);
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        return self.flat_index(position).map(|index| &self.cells[index]);
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        return self.flat_index(position).map(|index| &mut self.cells[index]);
+    }
+
+    // Returns None if moving from position by offset leaves the grid
+    pub fn offset(&self, position: Position, offset: Offset) -> Option<Position> {
+        return position.add_bounded(offset, self.bounds());
+    }
+
+    // All positions in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let num_cols = self.num_cols;
+
+        return (0..self.cells.len()).map(move |index| Position::new(index / num_cols, index % num_cols));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        assert!(row < self.num_rows, "Row {row} out of bounds for a grid with {} rows", self.num_rows);
+
+        return self.cells[row * self.num_cols..(row + 1) * self.num_cols].iter();
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.num_cols, "Column {col} out of bounds for a grid with {} columns", self.num_cols);
+
+        return self.cells.iter().skip(col).step_by(self.num_cols);
+    }
+
+    // Positions from start (inclusive) in the direction of offset, until the edge of the grid
+    pub fn ray(&self, start: Position, offset: Offset) -> impl Iterator<Item = Position> + '_ {
+        let start = self.contains(start).then_some(start);
+
+        return std::iter::successors(start, move |&position| self.offset(position, offset));
+    }
+
+    // Top left to bottom right, through start
+    pub fn diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.assert_contains(start);
+
+        let steps_back = start.row.min(start.col);
+        let first = Position::new(start.row - steps_back, start.col - steps_back);
+
+        return self.ray(first, Diagonal::DownRight.offset()).map(|position| &self[position]);
+    }
+
+    // Top right to bottom left, through start
+    pub fn anti_diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.assert_contains(start);
+
+        let steps_back = start.row.min(self.num_cols - 1 - start.col);
+        let first = Position::new(start.row - steps_back, start.col + steps_back);
+
+        return self.ray(first, Diagonal::DownLeft.offset()).map(|position| &self[position]);
+    }
+
+    fn neighbours<'a>(&'a self, position: Position, offsets: &'a [Offset]) -> impl Iterator<Item = Position> + 'a {
+        return offsets.iter().filter_map(move |&offset| self.offset(position, offset));
+    }
+
+    // Up, right, down and left, as far as they're inside the grid
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        return self.neighbours(position, &FOUR_OFFSETS);
+    }
+
+    // Including the diagonals
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        return self.neighbours(position, &EIGHT_OFFSETS);
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        return self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position);
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        return self.iter().filter(move |(_, cell)| *cell == value).map(|(position, _)| position);
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Self, GridError> {
+        return Grid::parse(input, |_, character| Ok(character));
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        return self.get(position)
+            .unwrap_or_else(|| panic!("Position {position:?} out of bounds for a grid of size {:?}", self.bounds()));
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let bounds = self.bounds();
+
+        return self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position {position:?} out of bounds for a grid of size {bounds:?}"));
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.num_rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction;
+
+    // 3 rows of 4
+    fn letters() -> Grid<char> {
+        return Grid::parse_chars("abcd\nefgh\nijkl").unwrap();
+    }
+
+    fn cells<'a>(iterator: impl Iterator<Item = &'a char>) -> String {
+        return iterator.collect();
+    }
+
+    #[test]
+    fn parse() {
+        let grid = letters();
+
+        assert_eq!(grid.bounds(), (3, 4));
+        assert_eq!(grid.num_rows(), 3);
+        assert_eq!(grid.num_cols(), 4);
+
+        assert_eq!(Grid::parse_chars("abc\nde\nfgh"), Err(GridError::RaggedLine { line: 2, text: "de".to_string(), expected: 3, found: 2 }));
+        assert_eq!(
+            Grid::parse_chars("abc\nde").unwrap_err().to_string(),
+            "line 2: expected 3 cells like the first line but found 2 in \"de\"",
+        );
+        assert_eq!(Grid::parse_chars(""), Err(GridError::Empty));
+        assert_eq!(Grid::parse_chars("\n\n"), Err(GridError::Empty));
+
+        // The mapper's own errors come through as they are, next to the structural ones
+        #[derive(Debug, PartialEq)]
+        enum DigitError {
+            Grid(GridError),
+            NotADigit(Position),
+        }
+
+        impl From<GridError> for DigitError {
+            fn from(error: GridError) -> Self {
+                return DigitError::Grid(error);
+            }
+        }
+
+        let digits = |input| Grid::parse(input, |position, character| character.to_digit(10).ok_or(DigitError::NotADigit(position)));
+
+        assert_eq!(digits("12\n34").unwrap().to_string(), "12\n34");
+        assert_eq!(digits("12\n3x"), Err(DigitError::NotADigit(Position::new(1, 1))));
+        assert_eq!(digits("12\n345"), Err(DigitError::Grid(GridError::RaggedLine { line: 2, text: "345".to_string(), expected: 2, found: 3 })));
+    }
+
+    #[test]
+    fn cells_and_edges() {
+        let mut grid = letters();
+
+        assert!(grid.contains(Position::new(2, 3)));
+        assert!(!grid.contains(Position::new(3, 0)));
+        assert!(!grid.contains(Position::new(0, 4)));
+
+        assert_eq!(grid.get(Position::new(2, 3)), Some(&'l'));
+        assert_eq!(grid.get(Position::new(0, 4)), None);
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid[Position::new(1, 2)], 'g');
+
+        *grid.get_mut(Position::new(0, 0)).unwrap() = 'A';
+        grid[Position::new(2, 3)] = 'L';
+
+        assert_eq!(grid.get_mut(Position::new(3, 3)), None);
+        assert_eq!(grid.to_string(), "Abcd\nefgh\nijkL");
+
+        let corner = Position::new(0, 0);
+
+        assert_eq!(grid.offset(corner, Direction::Right.offset()), Some(Position::new(0, 1)));
+        assert_eq!(grid.offset(corner, Direction::Up.offset()), None);
+        assert_eq!(grid.offset(corner, Direction::Left.offset()), None);
+        assert_eq!(grid.offset(Position::new(2, 3), Direction::Down.offset()), None);
+        assert_eq!(grid.offset(Position::new(2, 3), Direction::Right.offset()), None);
+        assert_eq!(grid.offset(Position::new(2, 3), Offset::new(-2, -3)), Some(corner));
+    }
+
+    #[test]
+    #[should_panic(expected = "Position { row: 3, col: 0 } out of bounds for a grid of size (3, 4)")]
+    fn index_out_of_bounds() {
+        let _ = letters()[Position::new(3, 0)];
+    }
+
+    #[test]
+    fn iterators() {
+        let grid = letters();
+
+        assert_eq!(grid.positions().count(), 12);
+        assert_eq!(grid.positions().nth(5), Some(Position::new(1, 1)));
+        assert_eq!(grid.iter().last(), Some((Position::new(2, 3), &'l')));
+
+        assert_eq!(cells(grid.row(0)), "abcd");
+        assert_eq!(cells(grid.row(2)), "ijkl");
+        assert_eq!(cells(grid.column(0)), "aei");
+        assert_eq!(cells(grid.column(3)), "dhl");
+
+        assert_eq!(grid.find(|&cell| cell > 'f'), Some(Position::new(1, 2)));
+        assert_eq!(grid.find(|&cell| cell == 'z'), None);
+
+        let repeated = Grid::parse_chars("#.#\n..#").unwrap();
+
+        assert_eq!(
+            repeated.positions_of(&'#').collect::<Vec<_>>(),
+            [Position::new(0, 0), Position::new(0, 2), Position::new(1, 2)],
+        );
+        assert_eq!(repeated.positions_of(&'x').count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 3 out of bounds for a grid with 3 rows")]
+    fn row_out_of_bounds() {
+        let _ = letters().row(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 4 out of bounds for a grid with 4 columns")]
+    fn column_out_of_bounds() {
+        let _ = letters().column(4);
+    }
+
+    #[test]
+    fn rays_and_diagonals() {
+        let grid = letters();
+        let at = |positions: &mut dyn Iterator<Item = Position>| positions.map(|position| grid[position]).collect::<String>();
+
+        assert_eq!(at(&mut grid.ray(Position::new(0, 1), Direction::Right.offset())), "bcd");
+        assert_eq!(at(&mut grid.ray(Position::new(2, 3), Offset::new(-1, -1))), "lgb");
+        assert_eq!(at(&mut grid.ray(Position::new(0, 0), Direction::Up.offset())), "a");
+        assert_eq!(at(&mut grid.ray(Position::new(1, 0), Offset::new(0, 2))), "eg");
+        assert_eq!(grid.ray(Position::new(3, 0), Direction::Up.offset()).count(), 0);
+
+        assert_eq!(cells(grid.diagonal(Position::new(0, 0))), "afk");
+        assert_eq!(cells(grid.diagonal(Position::new(2, 3))), "bgl");
+        assert_eq!(cells(grid.diagonal(Position::new(1, 0))), "ej");
+        assert_eq!(cells(grid.diagonal(Position::new(0, 3))), "d");
+
+        assert_eq!(cells(grid.anti_diagonal(Position::new(0, 3))), "dgj");
+        assert_eq!(cells(grid.anti_diagonal(Position::new(2, 1))), "dgj");
+        assert_eq!(cells(grid.anti_diagonal(Position::new(2, 3))), "l");
+        assert_eq!(cells(grid.anti_diagonal(Position::new(0, 0))), "a");
+        assert_eq!(cells(grid.anti_diagonal(Position::new(1, 0))), "be");
+    }
+
+    #[test]
+    #[should_panic(expected = "Position { row: 0, col: 4 } out of bounds for a grid of size (3, 4)")]
+    fn diagonal_out_of_bounds() {
+        let _ = letters().anti_diagonal(Position::new(0, 4));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = letters();
+        let sorted = |positions: &mut dyn Iterator<Item = Position>| {
+            let mut positions = positions.map(|position| grid[position]).collect::<Vec<_>>();
+
+            positions.sort();
+
+            return positions.into_iter().collect::<String>();
+        };
+
+        assert_eq!(sorted(&mut grid.neighbours_4(Position::new(0, 0))), "be");
+        assert_eq!(sorted(&mut grid.neighbours_4(Position::new(2, 3))), "hk");
+        assert_eq!(sorted(&mut grid.neighbours_4(Position::new(1, 1))), "begj");
+
+        assert_eq!(sorted(&mut grid.neighbours_8(Position::new(0, 0))), "bef");
+        assert_eq!(sorted(&mut grid.neighbours_8(Position::new(2, 3))), "ghk");
+        assert_eq!(sorted(&mut grid.neighbours_8(Position::new(1, 1))), "abcegijk");
+    }
+}
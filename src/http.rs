@@ -0,0 +1,239 @@
+use std::io::{BufRead, BufReader, Read, Write};
+
+// Just enough HTTP/1.1 for the puzzle client and the mock server: one request per connection, bodies with
+// Content-Length or chunked transfer encoding, no TLS. No need for an HTTP library for two endpoints
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    // `GET /2024/day/6/input HTTP/1.1` or `HTTP/1.1 200 OK`
+    pub start_line: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Message {
+    // Header names are case insensitive
+    pub fn header(&self, name: &str) -> Option<&str> {
+        return self.headers.iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str());
+    }
+
+    // The value of one cookie in the Cookie header
+    pub fn cookie(&self, name: &str) -> Option<&str> {
+        return self.header("Cookie")?
+            .split(';')
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find(|(cookie, _)| *cookie == name)
+            .map(|(_, value)| value);
+    }
+
+    pub fn write_to(&self, stream: &mut impl Write) -> std::io::Result<()> {
+        let mut head = format!("{}\r\n", self.start_line);
+
+        for (name, value) in &self.headers {
+            head.push_str(&format!("{name}: {value}\r\n"));
+        }
+
+        head.push_str(&format!("Content-Length: {}\r\nConnection: close\r\n\r\n", self.body.len()));
+
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(self.body.as_bytes())?;
+
+        return stream.flush();
+    }
+}
+
+// Far more than any input, but the lengths come from the other side and are only allocated up to this
+pub const MAX_BODY: usize = 16 * 1024 * 1024;
+
+fn invalid(message: impl Into<String>) -> std::io::Error {
+    return std::io::Error::new(std::io::ErrorKind::InvalidData, message.into());
+}
+
+// A line without its \r\n
+fn read_line(reader: &mut impl BufRead) -> std::io::Result<String> {
+    let mut line = String::new();
+
+    if reader.read_line(&mut line)? == 0 {
+        return Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "connection closed in the middle of a message"));
+    }
+
+    return Ok(line.trim_end_matches(['\r', '\n']).to_string());
+}
+
+fn too_large(length: usize) -> std::io::Error {
+    return invalid(format!("body of {length} bytes is larger than the limit of {MAX_BODY}"));
+}
+
+fn read_chunked(reader: &mut impl BufRead) -> std::io::Result<Vec<u8>> {
+    let mut body = vec![];
+
+    loop {
+        let size_line = read_line(reader)?;
+        // Chunk extensions after a ; are allowed and meaningless here
+        let size = size_line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16).map_err(|_| invalid(format!("invalid chunk size {size_line:?}")))?;
+
+        if size == 0 {
+            // Trailers, up to the empty line
+            while !read_line(reader)?.is_empty() {}
+
+            return Ok(body);
+        }
+
+        let start = body.len();
+
+        if size > MAX_BODY - start {
+            return Err(too_large(start.saturating_add(size)));
+        }
+
+        body.resize(start + size, 0);
+        reader.read_exact(&mut body[start..])?;
+
+        read_line(reader)?;
+    }
+}
+
+pub fn read_message(stream: impl Read) -> std::io::Result<Message> {
+    let mut reader = BufReader::new(stream);
+
+    let start_line = read_line(&mut reader)?;
+    let mut headers = vec![];
+
+    loop {
+        let line = read_line(&mut reader)?;
+
+        if line.is_empty() {
+            break;
+        }
+
+        let (name, value) = line.split_once(':').ok_or_else(|| invalid(format!("invalid header {line:?}")))?;
+
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
+
+    let mut message = Message { start_line, headers, body: String::new() };
+
+    let body = if message.header("Transfer-Encoding").is_some_and(|encoding| encoding.eq_ignore_ascii_case("chunked")) {
+        read_chunked(&mut reader)?
+    } else if let Some(length) = message.header("Content-Length") {
+        let length = length.parse::<usize>().map_err(|_| invalid(format!("invalid Content-Length {length:?}")))?;
+
+        if length > MAX_BODY {
+            return Err(too_large(length));
+        }
+
+        let mut body = vec![0; length];
+
+        reader.read_exact(&mut body)?;
+
+        body
+    } else if message.start_line.starts_with("HTTP/") {
+        // A response without a length ends when the connection does
+        let mut body = vec![];
+
+        reader.by_ref().take(MAX_BODY as u64 + 1).read_to_end(&mut body)?;
+
+        if body.len() > MAX_BODY {
+            return Err(too_large(body.len()));
+        }
+
+        body
+    } else {
+        // And a request without one has no body
+        vec![]
+    };
+
+    message.body = String::from_utf8(body).map_err(|_| invalid("body isn't UTF-8"))?;
+
+    return Ok(message);
+}
+
+// `application/x-www-form-urlencoded` values, for the answer form
+pub fn form_encode(text: &str) -> String {
+    let mut result = String::new();
+
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => result.push(byte as char),
+            b' ' => result.push('+'),
+            _ => result.push_str(&format!("%{byte:02X}")),
+        }
+    }
+
+    return result;
+}
+
+// The other way around, None when a % isn't followed by two hex digits
+pub fn form_decode(text: &str) -> Option<String> {
+    let mut bytes = vec![];
+    let mut iter = text.bytes();
+
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let digits = [iter.next()?, iter.next()?];
+
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&digits).ok()?, 16).ok()?);
+            },
+            _ => bytes.push(byte),
+        }
+    }
+
+    return String::from_utf8(bytes).ok();
+}
+
+// `level=1&answer=4602` -> [("level", "1"), ("answer", "4602")]
+pub fn parse_form(body: &str) -> Option<Vec<(String, String)>> {
+    return body.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+
+            return Some((form_decode(name)?, form_decode(value)?));
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_messages() {
+        let request = read_message(&b"POST /2024/day/6/answer HTTP/1.1\r\nCookie: a=1; session=abc\r\nContent-Length: 5\r\n\r\nlevel"[..]).unwrap();
+
+        assert_eq!(request.start_line, "POST /2024/day/6/answer HTTP/1.1");
+        assert_eq!(request.header("content-length"), Some("5"));
+        assert_eq!(request.cookie("session"), Some("abc"));
+        assert_eq!(request.body, "level");
+
+        let response = read_message(&b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n1 2 \r\n3\r\n3 4\r\n0\r\n\r\n"[..]).unwrap();
+
+        assert_eq!(response.body, "1 2 3 4");
+    }
+
+    #[test]
+    fn oversized_bodies() {
+        let huge = format!("POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n", usize::MAX);
+
+        assert_eq!(read_message(huge.as_bytes()).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+        let chunked = format!("HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nabcd\r\n{:x}\r\n", MAX_BODY);
+
+        assert_eq!(read_message(chunked.as_bytes()).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn forms() {
+        let body = format!("level=2&answer={}", form_encode("a b&c=d"));
+
+        assert_eq!(body, "level=2&answer=a+b%26c%3Dd");
+        assert_eq!(
+            parse_form(&body),
+            Some(vec![("level".to_string(), "2".to_string()), ("answer".to_string(), "a b&c=d".to_string())]),
+        );
+        assert_eq!(parse_form("answer=%4"), None);
+    }
+}
@@ -0,0 +1,232 @@
+use std::{io::{BufRead, BufReader, Read}, path::{Path, PathBuf}};
+
+use crate::profile::Profile;
+
+pub const INPUT_USAGE: &str = "[--input <path> | --example | -] [--profile <name>]";
+
+// Where a day reads its puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // The profile's input for the day, see `Profile::input_path`
+    Default,
+    File(PathBuf),
+    // The example from the puzzle text that's embedded in the day's module
+    Example,
+    Stdin,
+}
+
+// Relative paths are taken from the crate root, so running from another directory finds the same files
+pub fn resolve(path: impl AsRef<Path>) -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+}
+
+// Removes `flag <value>` from the arguments. Some(None) if the flag isn't there, None if its value is missing
+pub fn take_value(arguments: &mut Vec<String>, flag: &str) -> Option<Option<String>> {
+    let Some(index) = arguments.iter().position(|argument| argument == flag) else {
+        return Some(None);
+    };
+
+    arguments.remove(index);
+
+    if index >= arguments.len() {
+        return None;
+    }
+
+    return Some(Some(arguments.remove(index)));
+}
+
+// Removes the flag from the arguments, returns whether it was there
+pub fn take_flag(arguments: &mut Vec<String>, flag: &str) -> bool {
+    let Some(index) = arguments.iter().position(|argument| argument == flag) else {
+        return false;
+    };
+
+    arguments.remove(index);
+
+    return true;
+}
+
+impl InputSource {
+    // Removes `--input <path>`, `--example` or `-` from the arguments and leaves the rest alone.
+    // Returns None when a flag is missing its path or more than one source is given
+    pub fn take_from(arguments: &mut Vec<String>) -> Option<InputSource> {
+        let mut source = None;
+        let mut remaining = vec![];
+        let mut iter = std::mem::take(arguments).into_iter();
+
+        while let Some(argument) = iter.next() {
+            let found = match argument.as_str() {
+                "--input" => InputSource::File(resolve(iter.next()?)),
+                "--example" => InputSource::Example,
+                "-" => InputSource::Stdin,
+                _ => {
+                    remaining.push(argument);
+
+                    continue;
+                }
+            };
+
+            if source.replace(found).is_some() {
+                return None;
+            }
+        }
+
+        *arguments = remaining;
+
+        return Some(source.unwrap_or(InputSource::Default));
+    }
+
+    // `default_path` is only used for the default source
+    pub fn read(&self, default_path: &Path, example: &str) -> std::io::Result<String> {
+        return match self {
+            InputSource::Default => std::fs::read_to_string(default_path),
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Example => Ok(example.to_string()),
+            InputSource::Stdin => {
+                let mut input = String::new();
+
+                std::io::stdin().read_to_string(&mut input)?;
+
+                Ok(input)
+            },
+        };
+    }
+
+    // Like `read`, but line by line as the day asks for it, for inputs too big to hold in memory
+    pub fn open<'a>(&self, default_path: &Path, example: &'a str) -> std::io::Result<Box<dyn BufRead + 'a>> {
+        return match self {
+            InputSource::Default => Ok(Box::new(BufReader::new(std::fs::File::open(default_path)?))),
+            InputSource::File(path) => Ok(Box::new(BufReader::new(std::fs::File::open(path)?))),
+            InputSource::Example => Ok(Box::new(example.as_bytes())),
+            InputSource::Stdin => Ok(Box::new(std::io::stdin().lock())),
+        };
+    }
+
+    // What the answers to this input are stored under, None for stdin since there's no telling what it was
+    pub fn key(&self, profile: &Profile) -> Option<String> {
+        return match self {
+            InputSource::Default => Some(profile.answers_key()),
+            InputSource::Example => Some("example".to_string()),
+            InputSource::File(path) => {
+                let relative = path.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap_or(path);
+
+                Some(relative.display().to_string())
+            },
+            InputSource::Stdin => None,
+        };
+    }
+
+    // For error messages, e.g. "invalid input in <description>"
+    pub fn describe(&self, default_path: &Path) -> String {
+        return match self {
+            InputSource::Default => default_path.display().to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Example => "the example".to_string(),
+            InputSource::Stdin => "stdin".to_string(),
+        };
+    }
+}
+
+// 64 bit FNV-1a of the input as hex, to tell inputs apart in reports without including them.
+// Hand rolled rather than std's hasher, whose output may change between Rust versions
+pub fn hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    return format!("{hash:016x}");
+}
+
+// The input source a single day binary was started with, and where the profile's input for the day is
+struct BinaryArguments {
+    program: String,
+    source: InputSource,
+    default_path: PathBuf,
+    // Whatever is left for the binary itself
+    rest: Vec<String>,
+}
+
+fn exit_with_usage(program: &str, extra_usage: &str) -> ! {
+    eprintln!("Usage: {program} {extra_usage}{INPUT_USAGE}");
+
+    std::process::exit(1);
+}
+
+fn binary_arguments(day: usize, extra_usage: &str) -> BinaryArguments {
+    let mut arguments = std::env::args();
+    let program = arguments.next().unwrap_or_default();
+    let mut rest = arguments.collect::<Vec<_>>();
+
+    let profile_name = take_value(&mut rest, "--profile");
+    let source = InputSource::take_from(&mut rest);
+
+    let (Some(profile_name), Some(source)) = (profile_name, source) else {
+        exit_with_usage(&program, extra_usage);
+    };
+
+    let profile = match Profile::load(profile_name) {
+        Ok(profile) => profile,
+        Err(error) => {
+            eprintln!("{error}");
+
+            std::process::exit(1);
+        }
+    };
+
+    let default_path = profile.input_path(day);
+
+    return BinaryArguments { program, source, default_path, rest };
+}
+
+// For the single day binaries, which take nothing but the input source and profile.
+// Exits with a message instead of returning when the arguments or the input are bad
+pub fn read_from_args(day: usize, example: &str) -> String {
+    let ((), input) = read_with_args(day, example, "", |rest| rest.is_empty().then_some(()));
+
+    return input;
+}
+
+// Like `read_from_args`, for binaries that take more arguments. `parse_rest` gets the arguments besides
+// the input and profile, `extra_usage` describes them
+pub fn read_with_args<T>(day: usize, example: &str, extra_usage: &str, parse_rest: impl FnOnce(&[String]) -> Option<T>) -> (T, String) {
+    let BinaryArguments { program, source, default_path, rest } = binary_arguments(day, extra_usage);
+
+    let Some(parsed) = parse_rest(&rest) else {
+        exit_with_usage(&program, extra_usage);
+    };
+
+    return match source.read(&default_path, example) {
+        Ok(input) => (parsed, input),
+        Err(error) => {
+            eprintln!("Couldn't read {}: {error}", source.describe(&default_path));
+
+            std::process::exit(1);
+        }
+    };
+}
+
+// Like `read_with_args`, but hands out a reader instead of the whole input, for binaries that stream huge inputs
+pub fn open_from_args<'a, T>(
+    day: usize,
+    example: &'a str,
+    extra_usage: &str,
+    parse_rest: impl FnOnce(&[String]) -> Option<T>,
+) -> (T, Box<dyn BufRead + 'a>) {
+    let BinaryArguments { program, source, default_path, rest } = binary_arguments(day, extra_usage);
+
+    let Some(parsed) = parse_rest(&rest) else {
+        exit_with_usage(&program, extra_usage);
+    };
+
+    return match source.open(&default_path, example) {
+        Ok(reader) => (parsed, reader),
+        Err(error) => {
+            eprintln!("Couldn't read {}: {error}", source.describe(&default_path));
+
+            std::process::exit(1);
+        }
+    };
+}
@@ -0,0 +1,17 @@
+pub mod answers;
+pub mod arithmetic;
+pub mod client;
+pub mod days;
+pub mod geometry;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod mock_server;
+pub mod parallel;
+pub mod parse;
+pub mod profile;
+pub mod report;
+pub mod scaffold;
+mod solution;
+
+pub use solution::{run, PartRun, Run, Solution};
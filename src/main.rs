@@ -1,3 +1,554 @@
+use std::time::{Duration, Instant};
+
+use aoc_2024::{
+    answers::{self, Answers, Verdict},
+    client::{Client, Submission},
+    days::{self, Day},
+    input::{self, InputSource, INPUT_USAGE},
+    mock_server::MockServer,
+    parallel,
+    profile::Profile,
+    scaffold,
+    Run,
+};
+
+fn usage() -> String {
+    return format!(
+        "Usage: aoc-2024 <day|all> [part] {INPUT_USAGE} [--record] [--format text|json] [--jobs <n>]
+       aoc-2024 new <day>
+       aoc-2024 fetch <day|all> [--profile <name>]
+       aoc-2024 submit <day> <part> [--profile <name>]
+       aoc-2024 serve --inputs <dir> [--answers <file>] [--port <n>]
+
+fetch and submit need AOC_SERVER (or `server` in the config file) set to an http:// URL, like a local
+serve or a proxy to https://adventofcode.com. HTTPS isn't supported.",
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct PartResult {
+    day: usize,
+    part: usize,
+    answer: String,
+    verdict: Verdict,
+    // Of the whole day, the same for both parts
+    parse_duration: Duration,
+    duration: Duration,
+    input_hash: String,
+}
+
+fn parse_day(argument: &str) -> Option<Vec<&'static Day>> {
+    if argument == "all" {
+        return Some(days::ALL.iter().collect());
+    }
+
+    let day = days::get(argument.parse::<usize>().ok()?)?;
+
+    return Some(vec![day]);
+}
+
+fn parse_part(argument: Option<&str>) -> Option<Vec<usize>> {
+    return match argument {
+        None => Some(vec![1, 2]),
+        Some("1") => Some(vec![1]),
+        Some("2") => Some(vec![2]),
+        Some(_) => None,
+    };
+}
+
+fn parse_format(argument: Option<String>) -> Option<Format> {
+    return match argument.as_deref() {
+        None | Some("text") => Some(Format::Text),
+        Some("json") => Some(Format::Json),
+        Some(_) => None,
+    };
+}
+
+fn parse_jobs(argument: Option<String>) -> Option<usize> {
+    return match argument {
+        None => Some(parallel::default_jobs()),
+        Some(argument) => argument.parse::<usize>().ok().filter(|&jobs| jobs > 0),
+    };
+}
+
+// Just enough JSON for the results, no need for a serialisation library
+fn json_string(text: &str) -> String {
+    let mut result = String::from("\"");
+
+    for character in text.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            character if character.is_control() => result.push_str(&format!("\\u{:04x}", character as u32)),
+            character => result.push(character),
+        }
+    }
+
+    result.push('"');
+
+    return result;
+}
+
+// One object per part in a JSON array. Answers are strings because they don't all fit in a double
+fn print_json(results: &[PartResult]) {
+    println!("[");
+
+    for (i, result) in results.iter().enumerate() {
+        let (status, expected) = match &result.verdict {
+            Verdict::Unknown => ("unknown", "null".to_string()),
+            Verdict::Correct => ("correct", json_string(&result.answer)),
+            Verdict::Wrong { expected } => ("wrong", json_string(expected)),
+        };
+
+        let separator = if i + 1 < results.len() { "," } else { "" };
+
+        println!(
+            "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"parse_time_ns\": {}, \"solve_time_ns\": {}, \"input_hash\": {}, \"status\": {}, \"expected\": {}}}{separator}",
+            result.day,
+            result.part,
+            json_string(&result.answer),
+            result.parse_duration.as_nanos(),
+            result.duration.as_nanos(),
+            json_string(&result.input_hash),
+            json_string(status),
+            expected,
+        );
+    }
+
+    println!("]");
+}
+
+fn print_summary(results: &[PartResult], parse_total: Duration, wall_clock: Duration) {
+    let answer_width = results.iter()
+        .map(|result| result.answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!();
+    println!("Day  Part  {:<answer_width$}     Time", "Answer");
+
+    for result in results {
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:1}  {:?}",
+            result.day,
+            result.part,
+            result.answer,
+            result.verdict.mark(),
+            result.duration,
+        );
+    }
+
+    let total = parse_total + results.iter().map(|result| result.duration).sum::<Duration>();
+
+    // Parts running side by side make the wall clock time shorter than the sum
+    println!("Total: {total:?} ({wall_clock:?} wall clock)");
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{}", usage());
+
+    std::process::exit(1);
+}
+
+fn load_profile(name: Option<String>) -> Profile {
+    return match Profile::load(name) {
+        Ok(profile) => profile,
+        Err(error) => {
+            eprintln!("{error}");
+
+            std::process::exit(1);
+        }
+    };
+}
+
+fn load_answers(path: &std::path::Path) -> Answers {
+    return match Answers::load(path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("Couldn't load {}: {error}", path.display());
+
+            std::process::exit(1);
+        }
+    };
+}
+
+fn connect(profile: &Profile) -> Client {
+    let Some(server) = &profile.server else {
+        eprintln!(
+            "No server to talk to, set AOC_SERVER or `server` in the config file to an http:// URL. \
+            HTTPS isn't supported, so for adventofcode.com that has to be a local proxy that talks HTTPS to it",
+        );
+
+        std::process::exit(1);
+    };
+
+    let Some(session) = profile.session() else {
+        eprintln!(
+            "No session cookie for profile {}, put it in {} or AOC_SESSION",
+            profile.name,
+            profile.session_path().display(),
+        );
+
+        std::process::exit(1);
+    };
+
+    return match Client::new(server, &session) {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("{error}");
+
+            std::process::exit(1);
+        }
+    };
+}
+
+// Downloads the inputs that aren't in the cache yet. Inputs never change, so there's no need to ask twice
+fn fetch(mut arguments: Vec<String>) {
+    let profile_name = input::take_value(&mut arguments, "--profile");
+
+    let (Some(profile_name), [day]) = (profile_name, arguments.as_slice()) else {
+        exit_with_usage();
+    };
+
+    let Some(days) = parse_day(day) else {
+        exit_with_usage();
+    };
+
+    let profile = load_profile(profile_name);
+    let client = connect(&profile);
+    let mut failed = false;
+
+    for day in days {
+        let path = profile.input_path(day.number);
+
+        if path.exists() {
+            println!("Day {:02}: already have {}", day.number, path.display());
+
+            continue;
+        }
+
+        let written = client.fetch_input(day.number)
+            .map_err(|error| error.to_string())
+            .and_then(|input| {
+                std::fs::create_dir_all(path.parent().unwrap()).map_err(|error| error.to_string())?;
+
+                return std::fs::write(&path, input).map_err(|error| error.to_string());
+            });
+
+        match written {
+            Ok(()) => println!("Day {:02}: wrote {}", day.number, path.display()),
+            Err(error) => {
+                eprintln!("Day {:02}: couldn't fetch the input: {error}", day.number);
+
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+// Solves one part on the profile's input and submits the answer. A correct answer is recorded in answers.toml
+fn submit(mut arguments: Vec<String>) {
+    let profile_name = input::take_value(&mut arguments, "--profile");
+
+    let (Some(profile_name), [day, part]) = (profile_name, arguments.as_slice()) else {
+        exit_with_usage();
+    };
+
+    let day = day.parse::<usize>().ok().and_then(days::get);
+    let part = parse_part(Some(part));
+
+    let (Some(day), Some(parts)) = (day, part) else {
+        exit_with_usage();
+    };
+
+    let profile = load_profile(profile_name);
+    let path = profile.input_path(day.number);
+
+    let run = std::fs::read_to_string(&path)
+        .map_err(|error| format!("couldn't read {}: {error}", path.display()))
+        .and_then(|input| (day.run)((&input, &input), &parts, false).map_err(|error| format!("invalid input in {}: {error}", path.display())));
+
+    let answer = match run {
+        Ok(run) => run.parts[0].answer.clone(),
+        Err(error) => {
+            eprintln!("Day {:02}: {error}", day.number);
+
+            std::process::exit(1);
+        }
+    };
+
+    let part = parts[0];
+
+    println!("Day {:02} part {part}: submitting {answer}", day.number);
+
+    let submission = match connect(&profile).submit(day.number, part, &answer) {
+        Ok(submission) => submission,
+        Err(error) => {
+            eprintln!("Couldn't submit: {error}");
+
+            std::process::exit(1);
+        }
+    };
+
+    println!("{submission}");
+
+    if submission != Submission::Correct {
+        std::process::exit(1);
+    }
+
+    let answers_path = answers::default_path();
+    let mut answers = load_answers(&answers_path);
+
+    answers.record(day.number, &profile.answers_key(), part, &answer);
+
+    if let Err(error) = answers.save(&answers_path) {
+        eprintln!("Couldn't save {}: {error}", answers_path.display());
+
+        std::process::exit(1);
+    }
+
+    println!("Recorded it in {}", answers_path.display());
+}
+
+fn serve(mut arguments: Vec<String>) {
+    let inputs = input::take_value(&mut arguments, "--inputs");
+    let answers_path = input::take_value(&mut arguments, "--answers");
+    let port = input::take_value(&mut arguments, "--port");
+
+    let (Some(Some(inputs)), Some(answers_path), Some(port)) = (inputs, answers_path, port) else {
+        exit_with_usage();
+    };
+
+    let port = match port.map(|port| port.parse::<u16>()) {
+        None => 2024,
+        Some(Ok(port)) => port,
+        Some(Err(_)) => exit_with_usage(),
+    };
+
+    if !arguments.is_empty() {
+        exit_with_usage();
+    }
+
+    let answers_path = answers_path.map(input::resolve).unwrap_or_else(answers::default_path);
+    let answers = load_answers(&answers_path);
+    let inputs = input::resolve(inputs);
+
+    let listener = match std::net::TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(error) => {
+            eprintln!("Couldn't listen on port {port}: {error}");
+
+            std::process::exit(1);
+        }
+    };
+
+    println!("Serving the inputs in {} on http://127.0.0.1:{port}", inputs.display());
+    println!("Answers are checked against {}", answers_path.display());
+
+    if let Err(error) = MockServer::new(inputs, answers).serve(listener) {
+        eprintln!("{error}");
+
+        std::process::exit(1);
+    }
+}
+
+fn new_day(argument: Option<&String>) {
+    let Some(day) = argument.and_then(|argument| argument.parse::<usize>().ok()) else {
+        exit_with_usage();
+    };
+
+    match scaffold::new_day(day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+
+            match Profile::load(None) {
+                Ok(profile) => println!("Put the input in {}", profile.input_path(day).display()),
+                Err(error) => eprintln!("{error}"),
+            }
+        },
+        Err(error) => {
+            eprintln!("Couldn't create day {day:02}: {error}");
+
+            std::process::exit(1);
+        }
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let mut arguments = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match arguments.first().map(String::as_str) {
+        Some("new") if arguments.len() <= 2 => return new_day(arguments.get(1)),
+        Some("fetch") => return fetch(arguments.split_off(1)),
+        Some("submit") => return submit(arguments.split_off(1)),
+        Some("serve") => return serve(arguments.split_off(1)),
+        _ => {},
+    }
+
+    let record = input::take_flag(&mut arguments, "--record");
+    let format = input::take_value(&mut arguments, "--format").and_then(parse_format);
+    let jobs = input::take_value(&mut arguments, "--jobs").and_then(parse_jobs);
+    let profile_name = input::take_value(&mut arguments, "--profile");
+    let source = InputSource::take_from(&mut arguments);
+    let days = arguments.first().and_then(|argument| parse_day(argument));
+    let parts = parse_part(arguments.get(1).map(String::as_str));
+
+    let (Some(source), Some(format), Some(jobs), Some(profile_name), Some(days), Some(parts)) =
+        (source, format, jobs, profile_name, days, parts)
+    else {
+        exit_with_usage();
+    };
+
+    if arguments.len() > 2 {
+        exit_with_usage();
+    }
+
+    // One file or stdin can't be the input for every day at once
+    if days.len() > 1 && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        eprintln!("--input and - need a single day");
+
+        std::process::exit(1);
+    }
+
+    let profile = load_profile(profile_name);
+
+    let answers_path = answers::default_path();
+    let mut answers = load_answers(&answers_path);
+
+    // Answers to stdin aren't stored, there's no way to tell it's the same input next time
+    let key = source.key(&profile);
+
+    if record && key.is_none() {
+        eprintln!("Answers for stdin can't be recorded");
+
+        std::process::exit(1);
+    }
+
+    let mut results = vec![];
+    let mut parse_total = Duration::ZERO;
+    let mut failed = false;
+
+    let start = Instant::now();
+
+    // Days go to the thread pool, and with more than one job both parts of a day run at the same time as well.
+    // Either way everything is printed in day order
+    let solve_day = |day: &&Day| -> Result<(Run, [String; 2]), String> {
+        let number = day.number;
+        let path = profile.input_path(number);
+        let origin = source.describe(&path);
+
+        let input = source.read(&path, day.examples.0)
+            .map_err(|error| format!("Day {number:02}: couldn't read {origin}: {error}"))?;
+
+        // Only the examples can differ between the parts, every other source is one input for both
+        let input_2 = match source {
+            InputSource::Example => day.examples.1,
+            _ => &input,
+        };
+
+        let run = (day.run)((&input, input_2), &parts, jobs > 1)
+            .map_err(|error| format!("Day {number:02}: invalid input in {origin}: {error}"))?;
+
+        return Ok((run, [input::hash(&input), input::hash(input_2)]));
+    };
+
+    let report_day = |(day, outcome): (usize, Result<(Run, [String; 2]), String>)| {
+        let (run, input_hashes) = match outcome {
+            Ok(outcome) => outcome,
+            Err(message) => {
+                eprintln!("{message}");
+
+                failed = true;
+
+                return;
+            }
+        };
+
+        if format == Format::Text {
+            println!("Day {day:02} parsed in {:?}", run.parse_duration);
+        }
+
+        parse_total += run.parse_duration;
+
+        for part in run.parts {
+            let verdict = match &key {
+                Some(key) => answers.check(day, key, part.part, &part.answer),
+                None => Verdict::Unknown,
+            };
+
+            if format == Format::Text {
+                match &verdict {
+                    Verdict::Unknown => println!("Day {day:02} part {}: {} ({:?})", part.part, part.answer, part.duration),
+                    Verdict::Correct => println!("Day {day:02} part {}: {} ✓ ({:?})", part.part, part.answer, part.duration),
+                    Verdict::Wrong { expected } => println!(
+                        "Day {day:02} part {}: {} ✗ expected {expected} ({:?})",
+                        part.part,
+                        part.answer,
+                        part.duration,
+                    ),
+                }
+            }
+
+            results.push(PartResult {
+                day,
+                part: part.part,
+                answer: part.answer,
+                verdict,
+                parse_duration: run.parse_duration,
+                duration: part.duration,
+                input_hash: input_hashes[part.part - 1].clone(),
+            });
+        }
+    };
+
+    parallel::run_ordered(&days, jobs, |day| (day.number, solve_day(day)), report_day);
+
+    let wall_clock = start.elapsed();
+
+    match format {
+        Format::Text => print_summary(&results, parse_total, wall_clock),
+        Format::Json => print_json(&results),
+    }
+
+    if record {
+        let key = key.unwrap();
+
+        for result in &results {
+            answers.record(result.day, &key, result.part, &result.answer);
+        }
+
+        if let Err(error) = answers.save(&answers_path) {
+            eprintln!("Couldn't save {}: {error}", answers_path.display());
+
+            std::process::exit(1);
+        }
+
+        // Not on stdout in JSON mode, where it would break the output
+        let message = format!("Recorded {} answers in {}", results.len(), answers_path.display());
+
+        match format {
+            Format::Text => println!("{message}"),
+            Format::Json => eprintln!("{message}"),
+        }
+    } else if results.iter().any(|result| matches!(result.verdict, Verdict::Wrong { .. })) {
+        // A changed answer is a regression until it's recorded on purpose
+        failed = true;
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
 }
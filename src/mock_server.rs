@@ -0,0 +1,143 @@
+use std::{collections::BTreeSet, net::TcpListener, path::PathBuf, time::Duration};
+
+use crate::{
+    answers::Answers,
+    client::YEAR,
+    http::{self, Message},
+};
+
+// A stand-in for the Advent of Code server, to try fetching and submitting without the network.
+// Inputs come from `<inputs>/day-06.txt`, the same layout as a profile's directory in the input cache,
+// and answers are checked against the `[day-06.input]` tables of a file like answers.toml.
+// Like the real one it wants a session cookie, but any will do
+#[derive(Debug, Clone)]
+pub struct MockServer {
+    inputs: PathBuf,
+    answers: Answers,
+    // (day, part) that were answered correctly, those don't take answers anymore
+    solved: BTreeSet<(usize, usize)>,
+}
+
+// How long a connection gets to send its request
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+// The sentences the client looks for, in pages like the real ones
+const RIGHT_ANSWER: &str = "That's the right answer!  You are <em>one gold star</em> closer.";
+const WRONG_ANSWER: &str = "That's not the right answer.";
+const WRONG_LEVEL: &str = "You don't seem to be solving the right level.  Did you already complete it?";
+
+fn response(status: &str, content_type: &str, body: String) -> Message {
+    return Message {
+        start_line: format!("HTTP/1.1 {status}"),
+        headers: vec![("Content-Type".to_string(), content_type.to_string())],
+        body,
+    };
+}
+
+fn page(text: &str) -> Message {
+    return response("200 OK", "text/html", format!("<html><body><main><article><p>{text}</p></article></main></body></html>\n"));
+}
+
+fn not_found() -> Message {
+    return response("404 Not Found", "text/plain", "404 Not Found\n".to_string());
+}
+
+// `/2024/day/6/input` -> (6, "input")
+fn parse_path(path: &str) -> Option<(usize, &str)> {
+    let rest = path.strip_prefix(&format!("/{YEAR}/day/"))?;
+    let (day, endpoint) = rest.split_once('/')?;
+
+    return Some((day.parse().ok()?, endpoint));
+}
+
+impl MockServer {
+    pub fn new(inputs: PathBuf, answers: Answers) -> MockServer {
+        return MockServer { inputs, answers, solved: BTreeSet::new() };
+    }
+
+    fn input(&self, day: usize) -> Message {
+        return match std::fs::read_to_string(self.inputs.join(format!("day-{day:02}.txt"))) {
+            Ok(input) => response("200 OK", "text/plain", input),
+            Err(_) => response(
+                "404 Not Found",
+                "text/plain",
+                "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n".to_string(),
+            ),
+        };
+    }
+
+    fn answer(&mut self, day: usize, body: &str) -> Message {
+        let form = http::parse_form(body).unwrap_or_default();
+        let field = |name: &str| form.iter().find(|(field, _)| field == name).map(|(_, value)| value.as_str());
+
+        let (Some(part), Some(answer)) = (field("level").and_then(|level| level.parse::<usize>().ok()), field("answer")) else {
+            return response("400 Bad Request", "text/plain", "400 Bad Request\n".to_string());
+        };
+
+        // Part 2 only opens up once part 1 is solved
+        let open = !self.solved.contains(&(day, part)) && (part == 1 || (part == 2 && self.solved.contains(&(day, 1))));
+
+        if !open {
+            return page(WRONG_LEVEL);
+        }
+
+        let Some(expected) = self.answers.get(day, "input", part) else {
+            return page(&format!("{WRONG_ANSWER}  The mock server has no answer for day {day} part {part}."));
+        };
+
+        if answer == expected {
+            self.solved.insert((day, part));
+
+            return page(RIGHT_ANSWER);
+        }
+
+        let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+            (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+            _ => "",
+        };
+
+        return page(&format!("{}{hint}.  Please wait one minute before trying again.", WRONG_ANSWER.trim_end_matches('.')));
+    }
+
+    pub fn handle(&mut self, request: &Message) -> Message {
+        let mut words = request.start_line.split_whitespace();
+
+        let (Some(method), Some(path)) = (words.next(), words.next()) else {
+            return response("400 Bad Request", "text/plain", "400 Bad Request\n".to_string());
+        };
+
+        if request.cookie("session").is_none_or(str::is_empty) {
+            return response("400 Bad Request", "text/plain", "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".to_string());
+        }
+
+        return match (method, parse_path(path)) {
+            ("GET", Some((day, "input"))) => self.input(day),
+            ("POST", Some((day, "answer"))) => self.answer(day, &request.body),
+            _ => not_found(),
+        };
+    }
+
+    // One connection at a time, forever. Plenty for a single person running the client
+    pub fn serve(mut self, listener: TcpListener) -> std::io::Result<()> {
+        for stream in listener.incoming() {
+            let mut stream = stream?;
+
+            // One request per connection and one connection at a time, so a client that never finishes its
+            // request would block everyone after it
+            if stream.set_read_timeout(Some(READ_TIMEOUT)).is_err() {
+                continue;
+            }
+
+            // A broken request only affects that one connection
+            let response = match http::read_message(&stream) {
+                Ok(request) => self.handle(&request),
+                Err(_) => response("400 Bad Request", "text/plain", "400 Bad Request\n".to_string()),
+            };
+
+            let _ = response.write_to(&mut stream);
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,86 @@
+use std::{collections::BTreeMap, sync::{atomic::{AtomicUsize, Ordering}, mpsc}};
+
+// How many threads to use when nobody says otherwise
+pub fn default_jobs() -> usize {
+    return std::thread::available_parallelism().map(|jobs| jobs.get()).unwrap_or(1);
+}
+
+// Runs `work` on every item using `jobs` threads and hands the results to `done` in the order of the items.
+// A result is handed over as soon as it and everything before it are finished, so output can be printed
+// while the slower items are still running without ending up shuffled
+pub fn run_ordered<T, R>(items: &[T], jobs: usize, work: impl Fn(&T) -> R + Sync, mut done: impl FnMut(R))
+where
+    T: Sync,
+    R: Send,
+{
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let next_item = &next_item;
+            let work = &work;
+
+            scope.spawn(move || {
+                loop {
+                    let index = next_item.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(item) = items.get(index) else {
+                        return;
+                    };
+
+                    // Only fails when the receiving end is gone, then nobody cares about the rest either
+                    if sender.send((index, work(item))).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        // Otherwise the receiver below would wait for this one forever
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut next_to_hand_over = 0;
+
+        for (index, result) in receiver {
+            finished.insert(index, result);
+
+            while let Some(result) = finished.remove(&next_to_hand_over) {
+                done(result);
+
+                next_to_hand_over += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_keep_their_order() {
+        let items = (0..50).collect::<Vec<u64>>();
+        let mut results = vec![];
+
+        // Later items finish first
+        run_ordered(&items, 8, |&item| {
+            std::thread::sleep(std::time::Duration::from_micros(50 - item));
+
+            return item * 2;
+        }, |result| results.push(result));
+
+        assert_eq!(results, items.iter().map(|item| item * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn no_items() {
+        let mut called = false;
+
+        run_ordered(&[] as &[usize], 4, |&item| item, |_| called = true);
+
+        assert!(!called);
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+// Where in the input something went wrong, both 1-based like an editor shows them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    // From 0-based indices, as handed out by `enumerate()` over lines and chars
+    pub fn at(line_index: usize, char_index: usize) -> Self {
+        return Location {
+            line: line_index + 1,
+            column: char_index + 1,
+        };
+    }
+
+    // Location of `token` inside `line`, `token` has to be a slice of `line` (e.g. from `split`)
+    pub fn of(line_index: usize, line: &str, token: &str) -> Self {
+        let byte_offset = token.as_ptr() as usize - line.as_ptr() as usize;
+
+        return Location::at(line_index, line[..byte_offset].chars().count());
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "line {}, column {}", self.line, self.column);
+    }
+}
+
+// A line of the little subset of TOML that answers.toml, the profile config and day 02's policy files are
+// written in: `[table]` headers and `key = value` pairs, with blank lines and # comments in between
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TomlLine<'a> {
+    // What's between the brackets, trimmed
+    Table(&'a str),
+    // Both trimmed, the value still as written
+    KeyValue(&'a str, &'a str),
+    // Anything else, for the caller to report with its own error
+    Invalid,
+}
+
+// The line without a # comment at the end. A # inside a string is just a character
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, character) in line.char_indices() {
+        match character {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {},
+        }
+    }
+
+    return line;
+}
+
+// Every line that isn't blank or only a comment, with its 1-based number and its trimmed text for errors
+pub fn toml_lines(text: &str) -> impl Iterator<Item = (usize, &str, TomlLine<'_>)> {
+    return text.lines().enumerate().filter_map(|(i, line)| {
+        let line = strip_comment(line).trim();
+
+        if line.is_empty() {
+            return None;
+        }
+
+        let parsed = match line.strip_prefix('[') {
+            Some(rest) => rest.strip_suffix(']').map_or(TomlLine::Invalid, |inner| TomlLine::Table(inner.trim())),
+            None => line.split_once('=').map_or(TomlLine::Invalid, |(key, value)| TomlLine::KeyValue(key.trim(), value.trim())),
+        };
+
+        return Some((i + 1, line, parsed));
+    });
+}
+
+// `"some text"` with \" and \\ escapes, or None if it isn't a string
+pub fn parse_string(text: &str) -> Option<String> {
+    let inner = text.strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut result = String::new();
+    let mut characters = inner.chars();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => result.push(characters.next().filter(|next| matches!(next, '"' | '\\'))?),
+            '"' => return None,
+            _ => result.push(character),
+        }
+    }
+
+    return Some(result);
+}
+
+// A string, or a bare number or boolean as it's written
+pub fn parse_value(text: &str) -> Option<String> {
+    if text.starts_with('"') {
+        return parse_string(text);
+    }
+
+    if text.is_empty() || text.contains(char::is_whitespace) {
+        return None;
+    }
+
+    return Some(text.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toml() {
+        let text = "# Settings\n\n[day-06.input]  # the real one\nkey = \"a # b\" # comment\nsteps = 3\nnonsense\n[broken\n";
+        let lines = toml_lines(text).collect::<Vec<_>>();
+
+        assert_eq!(lines, [
+            (3, "[day-06.input]", TomlLine::Table("day-06.input")),
+            (4, "key = \"a # b\"", TomlLine::KeyValue("key", "\"a # b\"")),
+            (5, "steps = 3", TomlLine::KeyValue("steps", "3")),
+            (6, "nonsense", TomlLine::Invalid),
+            (7, "[broken", TomlLine::Invalid),
+        ]);
+
+        assert_eq!(parse_string(r#""say \"hi\" \\o/""#), Some(r#"say "hi" \o/"#.to_string()));
+        assert_eq!(parse_string(r#""a"b""#), None);
+        assert_eq!(parse_string("plain"), None);
+        assert_eq!(parse_value("true"), Some("true".to_string()));
+        assert_eq!(parse_value("\"either\""), Some("either".to_string()));
+        assert_eq!(parse_value("1 2"), None);
+    }
+}
@@ -0,0 +1,235 @@
+use std::{fmt::Display, path::{Path, PathBuf}};
+
+use crate::{input, parse::{self, TomlLine}};
+
+// Whose puzzle inputs to use. Every profile has its own directory in the input cache, outside of the source tree:
+//
+// <input dir>/<profile>/day-06.txt
+//
+// The input dir is AOC_INPUT_DIR, or `input_dir` from the config file, or ~/.cache/aoc-2024/inputs.
+// The profile is --profile, or AOC_PROFILE, or `profile` from the config file, or "default".
+// The server that `fetch` and `submit` talk to is AOC_SERVER, or `server` from the config file. There's no
+// default: the client only speaks plain HTTP and the real one is HTTPS only, so it has to be a local `serve`
+// or a proxy that talks HTTPS to adventofcode.com.
+// The config file is AOC_CONFIG or ~/.config/aoc-2024/config.toml and looks like this:
+//
+// input_dir = "/home/me/aoc/inputs"
+// profile = "me"
+// server = "http://localhost:2024"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    pub input_dir: PathBuf,
+    pub server: Option<String>,
+}
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    Unreadable { path: PathBuf, error: String },
+    InvalidLine { path: PathBuf, line: usize, text: String },
+    UnknownKey { path: PathBuf, line: usize, key: String },
+    // Profile names end up in paths and in answers.toml, so they're kept simple
+    InvalidProfileName(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ConfigError::Unreadable { path, error } => write!(f, "couldn't read {}: {error}", path.display()),
+            ConfigError::InvalidLine { path, line, text } => {
+                write!(f, "{} line {line}: expected key = \"value\" but found {text:?}", path.display())
+            },
+            ConfigError::UnknownKey { path, line, key } => {
+                write!(f, "{} line {line}: unknown key {key:?}, only input_dir, profile and server are supported", path.display())
+            },
+            ConfigError::InvalidProfileName(name) => {
+                write!(f, "invalid profile name {name:?}, only letters, digits, - and _ are allowed")
+            },
+        };
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Config {
+    input_dir: Option<PathBuf>,
+    profile: Option<String>,
+    server: Option<String>,
+}
+
+// $XDG_<kind>_HOME, falling back to ~/.<kind>
+fn user_dir(variable: &str, fallback: &str) -> Option<PathBuf> {
+    if let Some(directory) = std::env::var_os(variable).filter(|directory| !directory.is_empty()) {
+        return Some(PathBuf::from(directory));
+    }
+
+    return std::env::var_os("HOME").map(|home| Path::new(&home).join(fallback));
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("AOC_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+
+    return user_dir("XDG_CONFIG_HOME", ".config").map(|directory| directory.join("aoc-2024/config.toml"));
+}
+
+fn default_input_dir() -> PathBuf {
+    return match user_dir("XDG_CACHE_HOME", ".cache") {
+        Some(directory) => directory.join("aoc-2024/inputs"),
+        // No home directory at all, e.g. in some CI containers. This one is in .gitignore
+        None => input::resolve("inputs"),
+    };
+}
+
+fn is_valid_profile_name(name: &str) -> bool {
+    return !name.is_empty() && name.chars().all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_'));
+}
+
+impl Config {
+    fn parse(text: &str, path: &Path) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+
+        for (line_number, line, parsed) in parse::toml_lines(text) {
+            let invalid_line = || ConfigError::InvalidLine {
+                path: path.to_path_buf(),
+                line: line_number,
+                text: line.to_string(),
+            };
+
+            let TomlLine::KeyValue(key, value) = parsed else {
+                return Err(invalid_line());
+            };
+
+            let value = parse::parse_string(value).ok_or_else(invalid_line)?;
+
+            match key {
+                "input_dir" => config.input_dir = Some(PathBuf::from(value)),
+                "profile" => config.profile = Some(value),
+                "server" => config.server = Some(value),
+                key => return Err(ConfigError::UnknownKey {
+                    path: path.to_path_buf(),
+                    line: line_number,
+                    key: key.to_string(),
+                }),
+            }
+        }
+
+        return Ok(config);
+    }
+
+    // A missing config file is the same as an empty one
+    fn load() -> Result<Config, ConfigError> {
+        let Some(path) = config_path() else {
+            return Ok(Config::default());
+        };
+
+        return match std::fs::read_to_string(&path) {
+            Ok(text) => Config::parse(&text, &path),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(ConfigError::Unreadable { path, error: error.to_string() }),
+        };
+    }
+}
+
+impl Profile {
+    // `name` is what was given on the command line, if anything
+    pub fn load(name: Option<String>) -> Result<Profile, ConfigError> {
+        let config = Config::load()?;
+
+        let name = name
+            .or_else(|| std::env::var("AOC_PROFILE").ok().filter(|name| !name.is_empty()))
+            .or(config.profile)
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+
+        if !is_valid_profile_name(&name) {
+            return Err(ConfigError::InvalidProfileName(name));
+        }
+
+        let input_dir = std::env::var_os("AOC_INPUT_DIR")
+            .filter(|directory| !directory.is_empty())
+            .map(PathBuf::from)
+            .or(config.input_dir)
+            .unwrap_or_else(default_input_dir);
+
+        let server = std::env::var("AOC_SERVER")
+            .ok()
+            .filter(|server| !server.is_empty())
+            .or(config.server);
+
+        return Ok(Profile { name, input_dir, server });
+    }
+
+    pub fn is_default(&self) -> bool {
+        return self.name == DEFAULT_PROFILE;
+    }
+
+    // Every profile, the default one included, only ever uses the cache. Inputs are personal, they don't
+    // belong in the source tree
+    pub fn input_path(&self, day: usize) -> PathBuf {
+        return self.input_dir.join(&self.name).join(format!("day-{day:02}.txt"));
+    }
+
+    // Every profile logs in with its own session cookie, from AOC_SESSION or the `session` file next to its inputs
+    pub fn session_path(&self) -> PathBuf {
+        return self.input_dir.join(&self.name).join("session");
+    }
+
+    pub fn session(&self) -> Option<String> {
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            if !session.is_empty() {
+                return Some(session);
+            }
+        }
+
+        let session = std::fs::read_to_string(self.session_path()).ok()?;
+
+        return Some(session.trim().to_string()).filter(|session| !session.is_empty());
+    }
+
+    // What the answers to this profile's inputs are stored under in answers.toml
+    pub fn answers_key(&self) -> String {
+        if self.is_default() {
+            return "input".to_string();
+        }
+
+        return format!("input-{}", self.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let path = Path::new("config.toml");
+
+        assert_eq!(
+            Config::parse("# Mine\ninput_dir = \"/tmp/inputs\"\n\nprofile = \"me\"\n", path),
+            Ok(Config {
+                input_dir: Some(PathBuf::from("/tmp/inputs")),
+                profile: Some("me".to_string()),
+                server: None,
+            }),
+        );
+        assert!(matches!(Config::parse("input_dir = /tmp", path), Err(ConfigError::InvalidLine { line: 1, .. })));
+        assert!(matches!(Config::parse("colour = \"blue\"", path), Err(ConfigError::UnknownKey { line: 1, .. })));
+    }
+
+    #[test]
+    fn paths_and_keys() {
+        let profile = Profile {
+            name: "me".to_string(),
+            input_dir: PathBuf::from("/tmp/inputs"),
+            server: None,
+        };
+
+        assert_eq!(profile.input_path(6), PathBuf::from("/tmp/inputs/me/day-06.txt"));
+        assert_eq!(profile.session_path(), PathBuf::from("/tmp/inputs/me/session"));
+        assert_eq!(profile.answers_key(), "input-me");
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::Display;
+
+// Tables for the reports that explain how an answer came about, either aligned for reading or as CSV
+// for a spreadsheet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Text,
+    Csv,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl TableFormat {
+    // `--format text|csv`, text when there's no flag
+    pub fn parse(argument: Option<&str>) -> Option<TableFormat> {
+        return match argument {
+            None | Some("text") => Some(TableFormat::Text),
+            Some("csv") => Some(TableFormat::Csv),
+            Some(_) => None,
+        };
+    }
+}
+
+// Quotes only when it has to
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+
+    return field.to_string();
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Table {
+        return Table {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            rows: vec![],
+        };
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        assert_eq!(row.len(), self.headers.len(), "Every row needs a field for every header");
+
+        self.rows.push(row);
+    }
+
+    // Numbers are right aligned, any other column left aligned. "-" counts as a number, for missing ones
+    pub fn to_text(&self) -> String {
+        let columns = (0..self.headers.len())
+            .map(|column| {
+                let width = std::iter::once(&self.headers)
+                    .chain(&self.rows)
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0);
+
+                let numeric = self.rows.iter().all(|row| row[column] == "-" || row[column].parse::<f64>().is_ok());
+
+                return (width, numeric);
+            })
+            .collect::<Vec<_>>();
+
+        return std::iter::once(&self.headers)
+            .chain(&self.rows)
+            .map(|row| {
+                let line = row.iter()
+                    .zip(&columns)
+                    .map(|(field, &(width, numeric))| match numeric {
+                        true => format!("{field:>width$}"),
+                        false => format!("{field:<width$}"),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("  ");
+
+                return line.trim_end().to_string();
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    pub fn to_csv(&self) -> String {
+        return std::iter::once(&self.headers)
+            .chain(&self.rows)
+            .map(|row| row.iter().map(|field| csv_field(field)).collect::<Vec<_>>().join(","))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    pub fn render(&self, format: TableFormat) -> String {
+        return match format {
+            TableFormat::Text => self.to_text(),
+            TableFormat::Csv => self.to_csv(),
+        };
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.to_text());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats() {
+        let mut table = Table::new(&["Pair", "Note"]);
+
+        table.push(vec!["1".to_string(), "a, \"b\"".to_string()]);
+        table.push(vec!["10".to_string(), "c".to_string()]);
+
+        assert_eq!(table.to_text(), "Pair  Note\n   1  a, \"b\"\n  10  c");
+        assert_eq!(table.to_csv(), "Pair,Note\n1,\"a, \"\"b\"\"\"\n10,c");
+    }
+}
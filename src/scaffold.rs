@@ -0,0 +1,155 @@
+use std::{fmt::Display, path::{Path, PathBuf}};
+
+use crate::input;
+
+// In the templates, `xx`/`XX` become the zero padded day and NUMBER the plain one
+const DAY_TEMPLATE: &str = include_str!("../templates/day-xx/day_xx.rs");
+const MAIN_TEMPLATE: &str = include_str!("../templates/day-xx/main.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(usize),
+    AlreadyExists(PathBuf),
+    // The file doesn't look like the scaffolder expects, so it won't guess where the new day goes
+    NoPlaceToRegister { path: PathBuf, marker: &'static str },
+    Io { path: PathBuf, error: std::io::Error },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "there is no day {day}, only 1 to 25"),
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::NoPlaceToRegister { path, marker } => {
+                write!(f, "couldn't find any lines starting with {marker:?} in {}", path.display())
+            },
+            ScaffoldError::Io { path, error } => write!(f, "{}: {error}", path.display()),
+        };
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+fn fill_in(template: &str, day: usize) -> String {
+    return template
+        .replace("xx", &format!("{day:02}"))
+        .replace("XX", &format!("{day:02}"))
+        .replace("NUMBER", &day.to_string());
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    return std::fs::read_to_string(path).map_err(|error| ScaffoldError::Io { path: path.to_path_buf(), error });
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    return std::fs::write(path, contents).map_err(|error| ScaffoldError::Io { path: path.to_path_buf(), error });
+}
+
+// The day number in a line like `pub mod day_07;`, where marker is `pub mod day_`
+fn day_in_line(line: &str, marker: &str) -> Option<usize> {
+    let rest = line.trim_start().strip_prefix(marker)?;
+    let digits = rest.chars().take_while(char::is_ascii_digit).collect::<String>();
+
+    return digits.parse().ok();
+}
+
+// Inserts new_line among the lines for the other days so they stay sorted by day
+fn insert_line(text: &str, marker: &'static str, day: usize, new_line: &str, path: &Path) -> Result<String, ScaffoldError> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+
+    let existing = lines.iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_in_line(line, marker)?)))
+        .collect::<Vec<_>>();
+
+    let Some(&(last_index, _)) = existing.last() else {
+        return Err(ScaffoldError::NoPlaceToRegister { path: path.to_path_buf(), marker });
+    };
+
+    let index = existing.iter()
+        .find(|(_, other_day)| *other_day > day)
+        .map(|&(i, _)| i)
+        .unwrap_or(last_index + 1);
+
+    lines.insert(index, new_line);
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+// Creates the module and binary for a day, and adds it to the runner, the real input tests and the benchmarks.
+// The input goes into the input cache, see `Profile`.
+// Returns every file that was created or changed
+pub fn new_day(day: usize) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let module_path = input::resolve(format!("src/days/day_{day:02}.rs"));
+    let bin_directory = input::resolve(format!("src/bin/day-{day:02}"));
+    let main_path = bin_directory.join("main.rs");
+    let days_path = input::resolve("src/days/mod.rs");
+    let tests_path = input::resolve("tests/real_inputs.rs");
+    let benches_path = input::resolve("benches/days.rs");
+
+    for path in [&module_path, &bin_directory] {
+        if path.exists() {
+            return Err(ScaffoldError::AlreadyExists(path.clone()));
+        }
+    }
+
+    // Work everything out before touching any file, so a failure doesn't leave half a day behind
+    let days = read(&days_path)?;
+    let days = insert_line(&days, "pub mod day_", day, &format!("pub mod day_{day:02};"), &days_path)?;
+    let days = insert_line(
+        &days,
+        "Day { number: ",
+        day,
+        &format!("    Day {{ number: {day}, run: run::<day_{day:02}::Day{day:02}>, examples: (day_{day:02}::EXAMPLE, day_{day:02}::EXAMPLE) }},"),
+        &days_path,
+    )?;
+
+    let tests = read(&tests_path)?;
+    let tests = insert_line(&tests, "day_", day, &format!("    day_{day:02}: {day};"), &tests_path)?;
+
+    let benches = read(&benches_path)?;
+    let benches = insert_line(&benches, "day_", day, &format!("    day_{day:02}::Day{day:02}: {day};"), &benches_path)?;
+
+    std::fs::create_dir_all(&bin_directory).map_err(|error| ScaffoldError::Io { path: bin_directory.clone(), error })?;
+
+    write(&module_path, &fill_in(DAY_TEMPLATE, day))?;
+    write(&main_path, &fill_in(MAIN_TEMPLATE, day))?;
+    write(&days_path, &days)?;
+    write(&tests_path, &tests)?;
+    write(&benches_path, &benches)?;
+
+    return Ok(vec![module_path, main_path, days_path, tests_path, benches_path]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_in_template() {
+        let main = fill_in(MAIN_TEMPLATE, 7);
+
+        assert!(main.contains("use aoc_2024::{days::day_07::{self, Day07}, input, Solution};"));
+        assert!(main.contains("input::read_from_args(7, day_07::EXAMPLE)"));
+    }
+
+    #[test]
+    fn insert_in_order() {
+        let path = Path::new("mod.rs");
+        let text = "use x;\n\npub mod day_01;\npub mod day_03;\n\nfn f() {}\n";
+
+        assert_eq!(
+            insert_line(text, "pub mod day_", 2, "pub mod day_02;", path).unwrap(),
+            "use x;\n\npub mod day_01;\npub mod day_02;\npub mod day_03;\n\nfn f() {}\n",
+        );
+        assert_eq!(
+            insert_line(text, "pub mod day_", 13, "pub mod day_13;", path).unwrap(),
+            "use x;\n\npub mod day_01;\npub mod day_03;\npub mod day_13;\n\nfn f() {}\n",
+        );
+        assert!(insert_line(text, "Day { number: ", 2, "", path).is_err());
+    }
+}
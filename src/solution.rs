@@ -0,0 +1,148 @@
+use std::{error::Error, fmt::Display, time::{Duration, Instant}};
+
+// A day's puzzle, parsed once and then solved for both parts from the parsed model
+pub trait Solution {
+    // Sync so that both parts can work from the same parsed input at the same time, which also means
+    // the parts can't sneak any shared mutable state in there
+    type Parsed: Sync;
+    // Whatever fits the puzzle, the runner, the tests and answers.toml only ever see the rendered answer.
+    // Prefer fixed width integers over usize for anything that can get big
+    type Answer1: Display;
+    type Answer2: Display;
+    // Malformed input is reported through this rather than panicking halfway through a solve
+    type Error: Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error>;
+
+    fn part_1(parsed: &Self::Parsed) -> Self::Answer1;
+
+    fn part_2(parsed: &Self::Parsed) -> Self::Answer2;
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: usize,
+    // Rendered, so that days with different answer types can be handled side by side
+    pub answer: String,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse_duration: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+fn solve<S: Solution>(parsed: &S::Parsed, part: usize) -> PartRun {
+    let start = Instant::now();
+
+    let answer = match part {
+        1 => S::part_1(parsed).to_string(),
+        2 => S::part_2(parsed).to_string(),
+        _ => panic!("There are only two parts, not {part}"),
+    };
+
+    return PartRun {
+        part,
+        answer,
+        duration: start.elapsed(),
+    };
+}
+
+// Parses the inputs and solves the requested parts (1 and/or 2), timing each step separately. `inputs` is
+// the input for each part, usually the same one twice. Only some examples differ between the parts, and
+// then the one for part 2 is parsed on its own.
+// With concurrent_parts the parts each get their own thread, the results are in the order of `parts` either way
+pub fn run<S: Solution>(inputs: (&str, &str), parts: &[usize], concurrent_parts: bool) -> Result<Run, Box<dyn Error>> {
+    let start = Instant::now();
+
+    let separate = inputs.0 != inputs.1;
+
+    let parsed_1 = match parts.contains(&1) || !separate {
+        true => Some(S::parse(inputs.0)?),
+        false => None,
+    };
+
+    let parsed_2 = match parts.contains(&2) && separate {
+        true => Some(S::parse(inputs.1)?),
+        false => None,
+    };
+
+    let parse_duration = start.elapsed();
+
+    let work = parts.iter()
+        .map(|&part| {
+            let parsed = match part {
+                2 if separate => parsed_2.as_ref(),
+                _ => parsed_1.as_ref(),
+            };
+
+            return (parsed.unwrap(), part);
+        })
+        .collect::<Vec<_>>();
+
+    let result = if concurrent_parts {
+        std::thread::scope(|scope| {
+            let handles = work.into_iter()
+                .map(|(parsed, part)| scope.spawn(move || solve::<S>(parsed, part)))
+                .collect::<Vec<_>>();
+
+            // A panicking part takes the whole run down, just like it would without threads
+            return handles.into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+                .collect();
+        })
+    } else {
+        work.into_iter().map(|(parsed, part)| solve::<S>(parsed, part)).collect()
+    };
+
+    return Ok(Run {
+        parse_duration,
+        parts: result,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    // Answers that aren't unsigned numbers, the parts each with their own type
+    struct Letters;
+
+    impl Solution for Letters {
+        type Parsed = String;
+        type Answer1 = String;
+        type Answer2 = i64;
+        type Error = Infallible;
+
+        fn parse(input: &str) -> Result<String, Infallible> {
+            return Ok(input.trim().to_string());
+        }
+
+        fn part_1(input: &String) -> String {
+            return input.to_uppercase();
+        }
+
+        fn part_2(input: &String) -> i64 {
+            return -(input.len() as i64);
+        }
+    }
+
+    #[test]
+    fn answers_of_any_type() {
+        let run = run::<Letters>(("abc\n", "abc\n"), &[2, 1], true).unwrap();
+
+        let answers = run.parts.iter().map(|part| (part.part, part.answer.as_str())).collect::<Vec<_>>();
+
+        assert_eq!(answers, [(2, "-3"), (1, "ABC")]);
+
+        // Each part on its own input
+        let separate = super::run::<Letters>(("abc", "abcdef"), &[1, 2], false).unwrap();
+
+        let answers = separate.parts.iter().map(|part| (part.part, part.answer.as_str())).collect::<Vec<_>>();
+
+        assert_eq!(answers, [(1, "ABC"), (2, "-6")]);
+    }
+}
@@ -0,0 +1,47 @@
+use std::convert::Infallible;
+
+use crate::Solution;
+
+pub const EXAMPLE: &str = "";
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Parsed = String;
+    // Any Display type will do: i64 for negative answers, u128 for huge ones, String for letters
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<String, Infallible> {
+        return Ok(input.to_string());
+    }
+
+    fn part_1(_input: &String) -> u64 {
+        todo!();
+    }
+
+    fn part_2(_input: &String) -> u64 {
+        todo!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fill in EXAMPLE and the answers from the puzzle text
+    #[test]
+    fn part_1_example() {
+        let parsed = DayXX::parse(EXAMPLE).unwrap();
+
+        assert_eq!(DayXX::part_1(&parsed), 0);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let parsed = DayXX::parse(EXAMPLE).unwrap();
+
+        assert_eq!(DayXX::part_2(&parsed), 0);
+    }
+}
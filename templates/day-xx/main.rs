@@ -0,0 +1,22 @@
+use aoc_2024::{days::day_xx::{self, DayXX}, input, Solution};
+
+fn main() {
+    let input = input::read_from_args(NUMBER, day_xx::EXAMPLE);
+
+    let parsed = match DayXX::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("Invalid input: {error}");
+
+            std::process::exit(1);
+        }
+    };
+
+    let result_1 = DayXX::part_1(&parsed);
+
+    println!("Part 1: {result_1}");
+
+    let result_2 = DayXX::part_2(&parsed);
+
+    println!("Part 2: {result_2}");
+}
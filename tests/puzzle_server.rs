@@ -0,0 +1,50 @@
+use std::{net::TcpListener, path::PathBuf};
+
+use aoc_2024::{
+    answers::Answers,
+    client::{Client, ClientError, Submission},
+    days,
+    mock_server::MockServer,
+};
+
+// Deletes the directory when it goes out of scope, also when an assertion failed
+struct TempDir(PathBuf);
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+// The whole fetch, solve and submit round trip against the mock server on a free port, with the example of day 1
+#[test]
+fn fetch_solve_submit() {
+    let inputs = TempDir(std::env::temp_dir().join(format!("aoc-2024-mock-server-{}", std::process::id())));
+
+    std::fs::create_dir_all(&inputs.0).unwrap();
+    std::fs::write(inputs.0.join("day-01.txt"), days::day_01::EXAMPLE).unwrap();
+
+    let answers = Answers::parse("[day-01.input]\npart-1 = \"11\"\npart-2 = \"31\"\n").unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    // Serves forever, it goes away with the test process. The directory stays with the test, to be deleted
+    let server = MockServer::new(inputs.0.clone(), answers);
+
+    std::thread::spawn(move || server.serve(listener));
+
+    let client = Client::new(&url, "some-session").unwrap();
+
+    let input = client.fetch_input(1).unwrap();
+    let run = (days::get(1).unwrap().run)((&input, &input), &[1, 2], false).unwrap();
+
+    assert_eq!(client.submit(1, 2, &run.parts[1].answer), Ok(Submission::WrongLevel));
+    assert_eq!(client.submit(1, 1, "12"), Ok(Submission::Wrong { hint: Some("too high".to_string()) }));
+    assert_eq!(client.submit(1, 1, &run.parts[0].answer), Ok(Submission::Correct));
+    assert_eq!(client.submit(1, 1, &run.parts[0].answer), Ok(Submission::WrongLevel));
+    assert_eq!(client.submit(1, 2, &run.parts[1].answer), Ok(Submission::Correct));
+
+    assert!(matches!(client.fetch_input(2), Err(ClientError::Status { status, .. }) if status.starts_with("404")));
+    assert!(matches!(Client::new(&url, "").unwrap().fetch_input(1), Err(ClientError::Status { status, .. }) if status.starts_with("400")));
+}
@@ -0,0 +1,63 @@
+use aoc_2024::{answers::Answers, days, profile::Profile};
+
+// Runs a day on the real input of the current profile and compares both parts with the answers recorded in answers.toml.
+// Inputs aren't in the repository, they have to be in the input cache. A missing one fails, it doesn't count as checked
+fn check(day: usize) {
+    let answers = Answers::load(&aoc_2024::answers::default_path()).unwrap();
+    let profile = Profile::load(None).unwrap();
+
+    let path = profile.input_path(day);
+
+    assert!(
+        path.exists(),
+        "No input for day {day:02} in {}, get it with `cargo run -- fetch {day}` or copy it there",
+        path.display(),
+    );
+
+    let input = std::fs::read_to_string(&path).unwrap_or_else(|error| panic!("Couldn't read {}: {error}", path.display()));
+
+    let run = (days::get(day).unwrap().run)((&input, &input), &[1, 2], true).unwrap();
+
+    for part in run.parts {
+        let expected = answers.get(day, &profile.answers_key(), part.part)
+            .unwrap_or_else(|| panic!("No answer recorded for day {day:02} part {} of profile {}", part.part, profile.name));
+
+        assert_eq!(part.answer, expected, "Day {day:02} part {}", part.part);
+    }
+}
+
+macro_rules! real_input_tests {
+    ($($name:ident: $day:literal;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day);
+            }
+        )*
+
+        // So a new day can't be added without its real input being checked
+        #[test]
+        fn every_day_is_covered() {
+            let covered: &[usize] = &[$($day),*];
+
+            for day in days::ALL {
+                assert!(covered.contains(&day.number), "Day {:02} has no real input test", day.number);
+            }
+        }
+    };
+}
+
+real_input_tests! {
+    day_01: 1;
+    day_02: 2;
+    day_03: 3;
+    day_04: 4;
+    day_05: 5;
+    day_06: 6;
+    day_07: 7;
+    day_08: 8;
+    day_09: 9;
+    day_10: 10;
+    day_11: 11;
+    day_12: 12;
+}